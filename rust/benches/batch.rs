@@ -0,0 +1,41 @@
+// criterion is not a dependency on wasm32, the bench is then an empty binary
+#[cfg(not(target_arch = "wasm32"))]
+mod bench {
+    use criterion::{BenchmarkId, Criterion, criterion_group};
+    use small_uid::SmallUid;
+    use std::hint::black_box;
+
+    /// The batch generation used before `batch_new` was reworked, kept as a baseline.
+    fn naive_batch_new(count: usize) -> Vec<SmallUid> {
+        let mut smalluids = Vec::new();
+        for _ in 0..count {
+            smalluids.push(SmallUid::new());
+        }
+        smalluids
+    }
+
+    fn batch(c: &mut Criterion) {
+        let mut group = c.benchmark_group("batch");
+        for count in [1, 16, 1024, 65536] {
+            group.bench_with_input(BenchmarkId::new("naive", count), &count, |b, &count| {
+                b.iter(|| naive_batch_new(black_box(count)))
+            });
+            group.bench_with_input(BenchmarkId::new("batch_new", count), &count, |b, &count| {
+                b.iter(|| SmallUid::batch_new(black_box(count)))
+            });
+            let mut smalluids = vec![SmallUid::default(); count];
+            group.bench_with_input(BenchmarkId::new("fill", count), &count, |b, _| {
+                b.iter(|| SmallUid::fill(black_box(&mut smalluids)))
+            });
+        }
+        group.finish();
+    }
+
+    criterion_group!(benches, batch);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+criterion::criterion_main!(bench::benches);
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,17 @@
+fn main() {
+    // Node-API addons need extra linker arguments on some platforms
+    #[cfg(feature = "node")]
+    napi_build::setup();
+
+    // Protobuf messages, and the gRPC service with the `grpc` feature, compiled without protoc
+    #[cfg(feature = "proto")]
+    {
+        println!("cargo:rerun-if-changed=proto/small_uid.proto");
+        let descriptors = protox::compile(["small_uid.proto"], ["proto"]).unwrap();
+        tonic_prost_build::configure()
+            .build_server(cfg!(feature = "grpc"))
+            .build_client(cfg!(feature = "grpc"))
+            .compile_fds(descriptors)
+            .unwrap();
+    }
+}
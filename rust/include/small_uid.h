@@ -0,0 +1,106 @@
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#ifndef SMALL_UID_H
+#define SMALL_UID_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Size of the buffer `small_uid_format` needs: 11 base64-url characters and a NUL.
+#define SMALL_UID_STRING_SIZE 12
+
+// Status returned by the C functions, mirrors `SmallUidError` plus errors specific to the FFI.
+typedef enum SmallUidStatus {
+  SMALL_UID_STATUS_OK = 0,
+  SMALL_UID_STATUS_SYSTEM_TIME = 1,
+  SMALL_UID_STATUS_TIMESTAMP_LIMIT = 2,
+  SMALL_UID_STATUS_RANDOM_SIZE_LIMIT = 3,
+  SMALL_UID_STATUS_NOT_A_BASE64_URL = 4,
+  SMALL_UID_STATUS_DECODE_SLICE = 5,
+  SMALL_UID_STATUS_VEC_TO_ARRAY = 6,
+  SMALL_UID_STATUS_INVALID_CHAR = 7,
+  SMALL_UID_STATUS_MONOTONIC_COUNTER_LIMIT = 8,
+  SMALL_UID_STATUS_RANDOM_SPACE_LIMIT = 9,
+  SMALL_UID_STATUS_INVALID_RANGE = 10,
+  SMALL_UID_STATUS_NOT_A_UUID_V7 = 11,
+  SMALL_UID_STATUS_I64_RANGE = 12,
+  SMALL_UID_STATUS_JS_SAFE_LIMIT = 13,
+  SMALL_UID_STATUS_BEFORE_EPOCH = 14,
+  // A pointer argument was NULL.
+  SMALL_UID_STATUS_NULL_POINTER = 100,
+  // The output buffer is smaller than `SMALL_UID_STRING_SIZE`.
+  SMALL_UID_STATUS_BUFFER_TOO_SMALL = 101,
+  // The input string is not valid UTF-8.
+  SMALL_UID_STATUS_INVALID_UTF8 = 102,
+} SmallUidStatus;
+
+typedef struct SmallUidMonotonicGenerator SmallUidMonotonicGenerator;
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns a static, NUL-terminated description of a status, "Unknown status" for any other
+// integer.
+const char *small_uid_status_message(int status);
+
+// Generates a new SmallUid into `out`.
+//
+// # Safety
+//
+// `out` must be NULL or valid for writes.
+enum SmallUidStatus small_uid_new(uint64_t *out);
+
+// Parses a NUL-terminated base64-url string into `out`.
+//
+// # Safety
+//
+// `string` must be NULL or point to a NUL-terminated string, `out` must be NULL or valid
+// for writes.
+enum SmallUidStatus small_uid_parse(const char *string, uint64_t *out);
+
+// Writes the base64-url encoding of `id` and a terminating NUL into `buffer`.
+//
+// `size` is the size of the buffer, at least `SMALL_UID_STRING_SIZE`.
+//
+// # Safety
+//
+// `buffer` must be NULL or valid for writes of `size` bytes.
+enum SmallUidStatus small_uid_format(uint64_t id, char *buffer, size_t size);
+
+// Returns the timestamp of `id` in milliseconds since the unix epoch.
+uint64_t small_uid_timestamp(uint64_t id);
+
+// Returns the 20 random bits of `id`.
+uint64_t small_uid_random(uint64_t id);
+
+// Creates a monotonic generator, to be released with `small_uid_monotonic_free`.
+struct SmallUidMonotonicGenerator *small_uid_monotonic_new(void);
+
+// Generates the next monotonic SmallUid into `out`, waiting for the next millisecond
+// when the counter is exhausted.
+//
+// # Safety
+//
+// `generator` must be NULL or come from `small_uid_monotonic_new` and not be used by
+// another thread at the same time, `out` must be NULL or valid for writes.
+enum SmallUidStatus small_uid_monotonic_generate(struct SmallUidMonotonicGenerator *generator,
+                                                 uint64_t *out);
+
+// Releases a generator created by `small_uid_monotonic_new`, NULL is ignored.
+//
+// # Safety
+//
+// `generator` must be NULL or come from `small_uid_monotonic_new` and not be freed twice.
+void small_uid_monotonic_free(struct SmallUidMonotonicGenerator *generator);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SMALL_UID_H */
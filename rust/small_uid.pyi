@@ -0,0 +1,35 @@
+from datetime import datetime
+from typing import Iterator
+
+class SmallUidError(ValueError): ...
+
+class SmallUid:
+    """A 64-bit sortable unique identifier."""
+
+    def __init__(self) -> None: ...
+    @classmethod
+    def parse(cls, value: str) -> SmallUid: ...
+    @classmethod
+    def from_int(cls, value: int) -> SmallUid: ...
+    @property
+    def timestamp(self) -> int: ...
+    @property
+    def random(self) -> int: ...
+    @property
+    def datetime(self) -> datetime: ...
+    def __int__(self) -> int: ...
+    def __lt__(self, other: SmallUid) -> bool: ...
+    def __le__(self, other: SmallUid) -> bool: ...
+    def __gt__(self, other: SmallUid) -> bool: ...
+    def __ge__(self, other: SmallUid) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class MonotonicGenerator(Iterator[SmallUid]):
+    """Monotonic generator of SmallUids, also an infinite iterator."""
+
+    def __init__(self) -> None: ...
+    def generate(self) -> SmallUid: ...
+    def generate_batch(self, count: int) -> list[SmallUid]: ...
+    def __next__(self) -> SmallUid: ...
+
+def parse(value: str) -> SmallUid: ...
@@ -0,0 +1,31 @@
+//! SQLite loadable extension registering the `small_uid*` SQL functions of
+//! `small_uid::rusqlite::register_functions`.
+//!
+//! It is its own crate because rusqlite's `loadable_extension` feature routes every SQLite call
+//! through the host's API, a library built with it cannot open connections of its own.
+
+use std::ffi::{c_char, c_int};
+
+use rusqlite::{
+    Connection, Result,
+    ffi::{sqlite3, sqlite3_api_routines},
+};
+
+/// Entry point of the extension, SQLite derives its name from `libsmall_uid_sqlite`.
+///
+/// # Safety
+///
+/// Must only be called by SQLite when loading the extension.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sqlite3_smalluidsqlite_init(
+    db: *mut sqlite3,
+    pz_err_msg: *mut *mut c_char,
+    p_api: *mut sqlite3_api_routines,
+) -> c_int {
+    fn init(connection: Connection) -> Result<bool> {
+        small_uid::rusqlite::register_functions(&connection)?;
+        Ok(false)
+    }
+    // SAFETY: the arguments come from SQLite, `init` only registers functions.
+    unsafe { Connection::extension_init2(db, pz_err_msg, p_api, init) }
+}
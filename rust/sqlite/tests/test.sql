@@ -0,0 +1,15 @@
+-- Loads the extension into the sqlite3 shell, run from rust/sqlite and compare with expected.txt:
+-- sqlite3 :memory: < tests/test.sql | diff - tests/expected.txt
+.bail on
+.load target/debug/libsmall_uid_sqlite
+
+CREATE TABLE events (id INTEGER PRIMARY KEY DEFAULT (small_uid_monotonic()), name TEXT);
+INSERT INTO events (name) VALUES ('a'), ('b'), ('c');
+SELECT group_concat(name, '') FROM (SELECT name FROM events ORDER BY id);
+SELECT count(*) FROM events WHERE small_uid_timestamp(id) > 1700000000000;
+
+SELECT small_uid() != small_uid();
+SELECT small_uid_timestamp(small_uid_from_text('GSntNvOw6n8'));
+SELECT small_uid_to_text(small_uid_from_text('GSntNvOw6n8'));
+SELECT small_uid_timestamp('GSntNvOw6n8');
+SELECT small_uid_to_text(NULL) IS NULL;
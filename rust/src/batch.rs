@@ -0,0 +1,221 @@
+use std::{thread::sleep, time::Duration};
+
+use rand::{Rng, rngs::ThreadRng};
+
+use crate::{
+    Error, SmallUid,
+    checking::{MAX_RANDOM, timestamp_check},
+    generation::{assemble, timestamp_gen},
+};
+
+/// Number of ids generated between two clock reads.
+///
+/// Batches take one timestamp per millisecond rather than one per id: seeing that the
+/// millisecond changed takes a clock read itself, so the clock is read once per chunk of 1024
+/// ids instead. A chunk takes a few µs to generate, so its ids carry the millisecond they were
+/// generated in.
+const CLOCK_READ_INTERVAL: usize = 1024;
+
+/// Number of used random numbers kept in a sorted list before switching to a bitmap.
+///
+/// Small batches then never allocate or clear the 128 KiB bitmap.
+const SPARSE_LIMIT: usize = 256;
+
+/// Buffer of 20-bit random numbers drawn from the thread rng in bulk.
+struct RandomPool {
+    rng: ThreadRng,
+    buffer: [u32; CLOCK_READ_INTERVAL],
+    /// Number of values drawn per refill, small batches only draw what they need.
+    refill: usize,
+    position: usize,
+}
+
+impl RandomPool {
+    fn new(count: usize) -> RandomPool {
+        let refill = count.clamp(1, CLOCK_READ_INTERVAL);
+        RandomPool {
+            rng: rand::rng(),
+            buffer: [0; CLOCK_READ_INTERVAL],
+            refill,
+            position: refill,
+        }
+    }
+
+    fn next(&mut self) -> u32 {
+        if self.position == self.refill {
+            self.rng.fill(&mut self.buffer[..self.refill]);
+            self.position = 0;
+        }
+        let random = self.buffer[self.position];
+        self.position += 1;
+        // 2^20 divides 2^32, so masking keeps the distribution uniform
+        random & MAX_RANDOM as u32
+    }
+}
+
+/// Set of the random numbers already used within one millisecond.
+///
+/// It starts as a sorted list and turns into a bitmap, one bit per value, once it holds more
+/// than `SPARSE_LIMIT` numbers. The bitmap is allocated on first use and only cleared when used.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct UsedRandoms {
+    sparse: Vec<u32>,
+    bits: Vec<u64>,
+    dense: bool,
+    len: usize,
+}
+
+impl UsedRandoms {
+    /// Marks the random number as used, returns false if it already was.
+    fn insert(&mut self, random: u32) -> bool {
+        if !self.dense {
+            let Err(index) = self.sparse.binary_search(&random) else {
+                return false;
+            };
+            if self.sparse.len() < SPARSE_LIMIT {
+                self.sparse.insert(index, random);
+                self.len += 1;
+                return true;
+            }
+            self.densify();
+        }
+        let word = &mut self.bits[random as usize / 64];
+        let mask = 1 << (random % 64);
+        if *word & mask != 0 {
+            return false;
+        }
+        *word |= mask;
+        self.len += 1;
+        true
+    }
+
+    /// Moves the sorted list into the bitmap.
+    fn densify(&mut self) {
+        if self.bits.is_empty() {
+            self.bits = vec![0; (MAX_RANDOM as usize + 1) / 64];
+        }
+        for random in self.sparse.drain(..) {
+            self.bits[random as usize / 64] |= 1 << (random % 64);
+        }
+        self.dense = true;
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn clear(&mut self) {
+        self.sparse.clear();
+        if self.dense {
+            self.bits.fill(0);
+            self.dense = false;
+        }
+        self.len = 0;
+    }
+}
+
+/// Keeps track of the timestamp of a batch and the random numbers already used in it.
+///
+/// A batch may be restricted to one partition of the random space, the low bits of
+/// every random number are then the partition index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BatchState {
+    pub(crate) timestamp: u64,
+    used: UsedRandoms,
+    partition: u32,
+    partition_mask: u32,
+    pub(crate) capacity: usize,
+}
+
+impl Default for BatchState {
+    fn default() -> Self {
+        BatchState::new(0, 1)
+    }
+}
+
+impl BatchState {
+    fn new(partition: u32, partitions: u32) -> BatchState {
+        debug_assert!(partitions.is_power_of_two() && partitions <= MAX_RANDOM as u32 + 1);
+        debug_assert!(partition < partitions);
+        BatchState {
+            timestamp: 0,
+            used: UsedRandoms::default(),
+            partition,
+            partition_mask: partitions - 1,
+            capacity: (MAX_RANDOM as usize + 1) / partitions as usize,
+        }
+    }
+
+    /// Reads the clock, never going backwards so ids of a batch stay sorted by timestamp.
+    pub(crate) fn tick(&mut self) -> Result<(), Error> {
+        let timestamp = timestamp_check(timestamp_gen()?)?;
+        if timestamp > self.timestamp {
+            self.timestamp = timestamp;
+            self.used.clear();
+        }
+        Ok(())
+    }
+
+    /// Returns true once every random number of the current millisecond is used.
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.used.len() >= self.capacity
+    }
+
+    /// Waits for the next millisecond once every random number of the current one is used.
+    fn wait_if_exhausted(&mut self) -> Result<(), Error> {
+        while self.is_exhausted() {
+            sleep(Duration::from_millis(1));
+            self.tick()?;
+        }
+        Ok(())
+    }
+
+    fn next(&mut self, pool: &mut RandomPool) -> Result<SmallUid, Error> {
+        self.wait_if_exhausted()?;
+        Ok(self.draw(|| pool.next()))
+    }
+
+    /// Assembles a SmallUid from a random number not used yet in the current millisecond.
+    ///
+    /// `random` returns 20-bit random numbers, the millisecond must not be exhausted.
+    pub(crate) fn draw(&mut self, mut random: impl FnMut() -> u32) -> SmallUid {
+        debug_assert!(!self.is_exhausted());
+        loop {
+            let random = random() & !self.partition_mask | self.partition;
+            if self.used.insert(random) {
+                return assemble(self.timestamp, random as u64);
+            }
+        }
+    }
+
+    /// Fills the slice, reading the clock once per chunk of `CLOCK_READ_INTERVAL` ids.
+    pub(crate) fn fill(&mut self, uids: &mut [SmallUid]) -> Result<(), Error> {
+        let mut pool = RandomPool::new(uids.len());
+        for chunk in uids.chunks_mut(CLOCK_READ_INTERVAL) {
+            self.tick()?;
+            for uid in chunk {
+                *uid = self.next(&mut pool)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fills the slice with SmallUids that are unique within the slice
+pub fn fill(uids: &mut [SmallUid]) -> Result<(), Error> {
+    fill_partition(uids, 0, 1)
+}
+
+/// Fills the slice with SmallUids whose random numbers all end with the bits of `partition`.
+///
+/// `partitions` must be a power of two, slices filled with different partitions never collide.
+pub fn fill_partition(uids: &mut [SmallUid], partition: u32, partitions: u32) -> Result<(), Error> {
+    BatchState::new(partition, partitions).fill(uids)
+}
+
+/// Appends `count` SmallUids that are unique within the appended ids
+pub fn extend_into(uids: &mut Vec<SmallUid>, count: usize) -> Result<(), Error> {
+    let start = uids.len();
+    uids.resize(start + count, SmallUid::default());
+    fill(&mut uids[start..]).inspect_err(|_| uids.truncate(start))
+}
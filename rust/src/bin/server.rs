@@ -0,0 +1,31 @@
+//! `small-uid-server` HTTP sidecar issuing SmallUids, see `small_uid::server`.
+//!
+//! Usage: `small-uid-server [ADDRESS]`, listening on `127.0.0.1:8080` by default.
+
+use std::process::ExitCode;
+
+use tokio::net::TcpListener;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let listener = match TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("error: cannot listen on {}: {}", address, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("listening on http://{}", address);
+    match small_uid::server::serve(listener).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! `small-uid` command-line tool to mint, decode and convert SmallUids.
+
+use std::{
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use jiff::Timestamp;
+use small_uid::{SmallUid, SmallUidError, SmallUidRange, datetime::SmallUidTime};
+
+#[derive(Parser)]
+#[command(
+    name = "small-uid",
+    version,
+    about = "Mint, decode and convert Small UIDs"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate new ids
+    Gen {
+        /// Number of ids to generate
+        #[arg(short = 'n', long, default_value_t = 1)]
+        count: usize,
+        /// Generate increasing ids with the monotonic generator
+        #[arg(long)]
+        monotonic: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::B64)]
+        format: Format,
+    },
+    /// Print the parts of an id
+    Decode {
+        id: String,
+        /// Input format, detected from the id when omitted
+        #[arg(long)]
+        from: Option<Format>,
+    },
+    /// Convert an id between formats
+    Convert {
+        id: String,
+        /// Output format
+        #[arg(long, value_enum)]
+        to: Format,
+        /// Input format, detected from the id when omitted
+        #[arg(long)]
+        from: Option<Format>,
+    },
+    /// Print the smallest and largest ids of a time range, bounds included
+    Range {
+        /// Start as RFC 3339 or milliseconds since the unix epoch
+        #[arg(long)]
+        from: String,
+        /// End as RFC 3339 or milliseconds since the unix epoch
+        #[arg(long)]
+        to: String,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::B64)]
+        format: Format,
+    },
+    /// Check every line of stdin is a valid id, print the invalid ones
+    Validate {
+        /// Input format, detected from each line when omitted
+        #[arg(long)]
+        from: Option<Format>,
+    },
+}
+
+/// Text representations of a SmallUid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Base64-url, 11 characters
+    B64,
+    /// Hexadecimal, 16 digits after `0x`
+    Hex,
+    /// Decimal u64
+    Dec,
+    /// Crockford base32, 13 characters
+    B32,
+}
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl Format {
+    /// Detects the format from the characters of the id.
+    ///
+    /// Ids made only of digits are decimal, even when a base32 or base64-url id could be too.
+    fn detect(id: &str) -> Result<Format, String> {
+        let is_b64 = |char: u8| char.is_ascii_alphanumeric() || char == b'-' || char == b'_';
+        let is_hex = |digits: &str| {
+            digits.len() == 16 && digits.bytes().all(|char| char.is_ascii_hexdigit())
+        };
+        if !id.is_empty() && id.bytes().all(|char| char.is_ascii_digit()) {
+            Ok(Format::Dec)
+        } else if id.strip_prefix("0x").is_some_and(is_hex) {
+            Ok(Format::Hex)
+        } else if id.len() == 11 && id.bytes().all(is_b64) {
+            Ok(Format::B64)
+        } else if id.len() == 13 && id.bytes().all(|char| crockford_digit(char).is_some()) {
+            Ok(Format::B32)
+        } else {
+            Err(format!(
+                "cannot detect the format of {:?}, pass it with --from",
+                id
+            ))
+        }
+    }
+
+    fn encode(self, uid: SmallUid) -> String {
+        let value = uid.to_u64();
+        match self {
+            Format::B64 => uid.to_string(),
+            Format::Hex => format!("{:#018x}", value),
+            Format::Dec => value.to_string(),
+            // 13 characters, the first one holds the top 4 bits
+            Format::B32 => (0..13)
+                .rev()
+                .map(|i| CROCKFORD[(value >> (i * 5)) as usize & 0x1F] as char)
+                .collect(),
+        }
+    }
+
+    fn decode(self, id: &str) -> Result<SmallUid, SmallUidError> {
+        match self {
+            Format::B64 => SmallUid::try_from(id.to_string()),
+            Format::Hex => {
+                let digits = id.strip_prefix("0x").unwrap_or(id);
+                if !digits.bytes().all(|char| char.is_ascii_hexdigit()) {
+                    return Err(SmallUidError::InvalidChar);
+                }
+                u64::from_str_radix(digits, 16)
+                    .map(SmallUid)
+                    .map_err(|_| SmallUidError::InvalidChar)
+            }
+            Format::Dec => id
+                .parse()
+                .map(SmallUid)
+                .map_err(|_| SmallUidError::InvalidChar),
+            Format::B32 => {
+                if id.len() != 13 {
+                    return Err(SmallUidError::InvalidChar);
+                }
+                let mut value: u64 = 0;
+                for (i, char) in id.bytes().enumerate() {
+                    let digit = crockford_digit(char).ok_or(SmallUidError::InvalidChar)?;
+                    // The first character only has room for 4 bits
+                    if i == 0 && digit > 0xF {
+                        return Err(SmallUidError::InvalidChar);
+                    }
+                    value = value << 5 | digit;
+                }
+                Ok(SmallUid(value))
+            }
+        }
+    }
+}
+
+/// Decodes a Crockford base32 digit, case-insensitive, reading I and L as 1 and O as 0.
+fn crockford_digit(char: u8) -> Option<u64> {
+    let char = match char.to_ascii_uppercase() {
+        b'I' | b'L' => b'1',
+        b'O' => b'0',
+        char => char,
+    };
+    CROCKFORD
+        .iter()
+        .position(|&digit| digit == char)
+        .map(|digit| digit as u64)
+}
+
+fn parse_id(id: &str, from: Option<Format>) -> Result<SmallUid, String> {
+    let id = id.trim();
+    let format = match from {
+        Some(format) => format,
+        None => Format::detect(id)?,
+    };
+    format.decode(id).map_err(|err| err.to_string())
+}
+
+/// Parses RFC 3339 or milliseconds since the unix epoch.
+fn parse_time(time: &str) -> Result<u64, String> {
+    if let Ok(millis) = time.parse::<u64>() {
+        return Ok(millis);
+    }
+    let timestamp: Timestamp = time
+        .parse()
+        .map_err(|err| format!("invalid time {:?}: {}", time, err))?;
+    timestamp.to_unix_millis().map_err(|err| err.to_string())
+}
+
+fn run(command: Command) -> Result<ExitCode, String> {
+    let mut stdout = io::stdout().lock();
+    let mut print = |line: String| writeln!(stdout, "{}", line).map_err(|err| err.to_string());
+    match command {
+        Command::Gen {
+            count,
+            monotonic,
+            format,
+        } => {
+            if monotonic {
+                let mut generator = SmallUid::init_monotonic();
+                for uid in generator.iter().take(count) {
+                    print(format.encode(uid))?;
+                }
+            } else {
+                for uid in SmallUid::batch_new(count) {
+                    print(format.encode(uid))?;
+                }
+            }
+        }
+        Command::Decode { id, from } => {
+            let uid = parse_id(&id, from)?;
+            print(uid.inspect().to_string())?;
+        }
+        Command::Convert { id, to, from } => {
+            let uid = parse_id(&id, from)?;
+            print(to.encode(uid))?;
+        }
+        Command::Range { from, to, format } => {
+            let range = SmallUidRange::from_timestamps(parse_time(&from)?..=parse_time(&to)?)
+                .map_err(|err| err.to_string())?;
+            print(format.encode(range.start()))?;
+            print(format.encode(range.end()))?;
+        }
+        Command::Validate { from } => {
+            let mut invalid = 0;
+            for (number, line) in io::stdin().lock().lines().enumerate() {
+                let line = line.map_err(|err| err.to_string())?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if let Err(err) = parse_id(&line, from) {
+                    invalid += 1;
+                    print(format!("{}: {}: {}", number + 1, line.trim(), err))?;
+                }
+            }
+            if invalid > 0 {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats_round_trip() {
+        for uid in [SmallUid(0), SmallUid(u64::MAX), SmallUid::new()] {
+            for format in [Format::B64, Format::Hex, Format::Dec, Format::B32] {
+                let encoded = format.encode(uid);
+                assert_eq!(format.decode(&encoded).unwrap(), uid);
+                // Digit-only ids are read as decimal
+                if !encoded.bytes().all(|char| char.is_ascii_digit()) {
+                    assert_eq!(Format::detect(&encoded).unwrap(), format);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_decimal() {
+        for id in ["12345678901", "1234567890123", "1234567890123456"] {
+            assert_eq!(Format::detect(id).unwrap(), Format::Dec);
+            assert_eq!(parse_id(id, None).unwrap(), SmallUid(id.parse().unwrap()));
+        }
+        assert_eq!(Format::detect("0x00000000000003e8").unwrap(), Format::Hex);
+        assert!(Format::detect("0123456789abcdef").is_err());
+        // A base64-url id may start with 0x too
+        assert_eq!(Format::detect("0xSntNvOw6n").unwrap(), Format::B64);
+        assert!(Format::Hex.decode("0x0x00000000000003e8").is_err());
+        assert!(Format::Hex.decode("0x+3e8").is_err());
+        assert!(Format::detect("").is_err());
+        assert_eq!(
+            parse_id("GSntNvOw6n8", Some(Format::B64)).unwrap(),
+            parse_id("GSntNvOw6n8", None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_b32_is_sortable() {
+        let a = Format::B32.encode(SmallUid(0x0FFF_FFFF));
+        let b = Format::B32.encode(SmallUid(0x1000_0000));
+        assert!(a < b);
+        assert_eq!(Format::B32.encode(SmallUid(u64::MAX)), "FZZZZZZZZZZZZ");
+        assert!(Format::B32.decode("G000000000000").is_err());
+        assert_eq!(
+            Format::B32.decode("0000000000o1l").unwrap(),
+            Format::B32.decode("0000000000011").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("1000").unwrap(), 1000);
+        assert_eq!(
+            parse_time("2023-11-14T22:13:20.123Z").unwrap(),
+            1_700_000_000_123
+        );
+        assert!(parse_time("1969-12-31T23:59:59Z").is_err());
+        assert!(parse_time("yesterday").is_err());
+    }
+}
@@ -1,8 +1,16 @@
 use crate::{Error, SmallUidError};
 
+/// Largest timestamp that fits in the 44-bit timestamp field.
+pub const MAX_TIMESTAMP: u64 = (1 << 44) - 1;
+
+/// Largest random number that fits in the 20-bit random field.
+pub const MAX_RANDOM: u64 = (1 << 20) - 1;
+
 /// Checks if the timestamp is within the 44-bit range.
-pub fn timestamp_check(timestamp: u64) -> Result<u64, Error> {
-    if timestamp < (1 << 44) - 1 {
+///
+/// `MAX_TIMESTAMP` itself is valid, it is the last millisecond representable by a SmallUid.
+pub const fn timestamp_check(timestamp: u64) -> Result<u64, Error> {
+    if timestamp <= MAX_TIMESTAMP {
         Ok(timestamp)
     } else {
         Err(SmallUidError::TimestampLimit)
@@ -10,8 +18,8 @@ pub fn timestamp_check(timestamp: u64) -> Result<u64, Error> {
 }
 
 /// Checks if the random number is within the 20-bit range.
-pub fn rng_size_check(rn: u64) -> Result<u64, Error> {
-    if rn < (1 << 20) {
+pub const fn rng_size_check(rn: u64) -> Result<u64, Error> {
+    if rn <= MAX_RANDOM {
         Ok(rn)
     } else {
         Err(SmallUidError::RandomSizeLimit)
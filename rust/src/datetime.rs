@@ -0,0 +1,245 @@
+use crate::{Error, SmallUid, SmallUidError, checking::timestamp_check, generation::random_gen};
+
+#[cfg(target_arch = "wasm32")]
+use web_time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Date and time types a SmallUid timestamp can be converted to and from.
+///
+/// Implemented for `SystemTime`, and for the types of the `chrono`, `time` and `jiff` crates
+/// when their features are enabled.
+pub trait SmallUidTime: Sized + PartialEq {
+    /// Creates the date and time from milliseconds since the unix epoch.
+    ///
+    /// Fails with `TimestampLimit` when the type cannot hold the time, every 44-bit
+    /// timestamp fits in the supported types.
+    fn from_unix_millis(millis: u64) -> Result<Self, Error>;
+
+    /// Returns the milliseconds since the unix epoch, truncating any sub-millisecond precision.
+    ///
+    /// The result is not checked against the 44-bit range yet.
+    fn to_unix_millis(&self) -> Result<u64, Error>;
+}
+
+/// Converts signed milliseconds since the unix epoch, failing before the epoch.
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+fn unsigned_millis(millis: i128) -> Result<u64, Error> {
+    if millis < 0 {
+        return Err(SmallUidError::BeforeEpoch);
+    }
+    u64::try_from(millis).map_err(|_| SmallUidError::TimestampLimit)
+}
+
+impl SmallUidTime for SystemTime {
+    fn from_unix_millis(millis: u64) -> Result<Self, Error> {
+        UNIX_EPOCH
+            .checked_add(Duration::from_millis(millis))
+            .ok_or(SmallUidError::TimestampLimit)
+    }
+
+    fn to_unix_millis(&self) -> Result<u64, Error> {
+        let since_epoch = self
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| SmallUidError::BeforeEpoch)?;
+        let millis = since_epoch.as_millis();
+        u64::try_from(millis).map_err(|_| SmallUidError::TimestampLimit)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SmallUidTime for chrono::DateTime<chrono::Utc> {
+    fn from_unix_millis(millis: u64) -> Result<Self, Error> {
+        let millis = i64::try_from(millis).map_err(|_| SmallUidError::TimestampLimit)?;
+        chrono::DateTime::from_timestamp_millis(millis).ok_or(SmallUidError::TimestampLimit)
+    }
+
+    fn to_unix_millis(&self) -> Result<u64, Error> {
+        unsigned_millis(self.timestamp_millis() as i128)
+    }
+}
+
+#[cfg(feature = "time")]
+impl SmallUidTime for time::OffsetDateTime {
+    fn from_unix_millis(millis: u64) -> Result<Self, Error> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000)
+            .map_err(|_| SmallUidError::TimestampLimit)
+    }
+
+    fn to_unix_millis(&self) -> Result<u64, Error> {
+        unsigned_millis(self.unix_timestamp_nanos().div_euclid(1_000_000))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl SmallUidTime for jiff::Timestamp {
+    fn from_unix_millis(millis: u64) -> Result<Self, Error> {
+        let millis = i64::try_from(millis).map_err(|_| SmallUidError::TimestampLimit)?;
+        jiff::Timestamp::from_millisecond(millis).map_err(|_| SmallUidError::TimestampLimit)
+    }
+
+    fn to_unix_millis(&self) -> Result<u64, Error> {
+        unsigned_millis(self.as_millisecond() as i128)
+    }
+}
+
+impl SmallUid {
+    /// Returns the timestamp of the SmallUid as a `SystemTime`.
+    pub fn datetime(&self) -> SystemTime {
+        self.to_datetime()
+    }
+
+    /// Creates a SmallUid from the provided `SystemTime` and a random number.
+    ///
+    /// Fails if the time is before the unix epoch or beyond the 44-bit timestamp range.
+    pub fn from_system_time(time: SystemTime) -> Result<SmallUid, Error> {
+        SmallUid::from_datetime(time)
+    }
+
+    /// Returns the timestamp of the SmallUid as any supported date and time type.
+    ///
+    /// ```
+    /// use small_uid::SmallUid;
+    /// use std::time::SystemTime;
+    ///
+    /// let id = SmallUid::new();
+    /// let time: SystemTime = id.to_datetime();
+    /// ```
+    pub fn to_datetime<T: SmallUidTime>(&self) -> T {
+        T::from_unix_millis(self.get_timestamp())
+            .expect("44-bit timestamps fit in every supported type")
+    }
+
+    /// Creates a SmallUid from any supported date and time type and a random number.
+    ///
+    /// Fails if the time is before the unix epoch or beyond the 44-bit timestamp range.
+    pub fn from_datetime<T: SmallUidTime>(datetime: T) -> Result<SmallUid, Error> {
+        let timestamp = timestamp_check(datetime.to_unix_millis()?)?;
+        SmallUid::from_parts_checked(timestamp, random_gen())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checking::MAX_TIMESTAMP;
+
+    #[test]
+    fn test_system_time_round_trip() {
+        let uid = SmallUid::new();
+        let time = uid.datetime();
+        let from_time = SmallUid::from_system_time(time).unwrap();
+        assert_eq!(from_time.get_timestamp(), uid.get_timestamp());
+    }
+
+    #[test]
+    fn test_system_time_truncates_sub_millisecond() {
+        let time = UNIX_EPOCH + Duration::from_micros(1_999);
+        let uid = SmallUid::from_system_time(time).unwrap();
+        assert_eq!(uid.get_timestamp(), 1);
+    }
+
+    #[test]
+    fn test_system_time_limits() {
+        let last = UNIX_EPOCH + Duration::from_millis(MAX_TIMESTAMP);
+        let uid = SmallUid::from_system_time(last).unwrap();
+        assert_eq!(uid.get_timestamp(), MAX_TIMESTAMP);
+        assert_eq!(uid.datetime(), last);
+
+        let beyond = UNIX_EPOCH + Duration::from_millis(MAX_TIMESTAMP + 1);
+        assert!(matches!(
+            SmallUid::from_system_time(beyond),
+            Err(SmallUidError::TimestampLimit)
+        ));
+
+        let before = UNIX_EPOCH - Duration::from_millis(1);
+        assert!(matches!(
+            SmallUid::from_system_time(before),
+            Err(SmallUidError::BeforeEpoch)
+        ));
+    }
+
+    #[test]
+    fn test_system_time_from_unix_millis() {
+        assert_eq!(
+            SystemTime::from_unix_millis(MAX_TIMESTAMP).unwrap(),
+            UNIX_EPOCH + Duration::from_millis(MAX_TIMESTAMP)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        use chrono::{DateTime, Utc};
+
+        let uid = SmallUid::new();
+        let datetime: DateTime<Utc> = uid.to_datetime();
+        assert_eq!(datetime.timestamp_millis() as u64, uid.get_timestamp());
+        let from_datetime = SmallUid::from_datetime(datetime).unwrap();
+        assert_eq!(from_datetime.get_timestamp(), uid.get_timestamp());
+
+        let last = DateTime::<Utc>::from_timestamp_millis(MAX_TIMESTAMP as i64).unwrap();
+        assert!(SmallUid::from_datetime(last).is_ok());
+        let beyond = DateTime::<Utc>::from_timestamp_millis(MAX_TIMESTAMP as i64 + 1).unwrap();
+        assert!(matches!(
+            SmallUid::from_datetime(beyond),
+            Err(SmallUidError::TimestampLimit)
+        ));
+        let before = DateTime::<Utc>::from_timestamp_millis(-1).unwrap();
+        assert!(matches!(
+            SmallUid::from_datetime(before),
+            Err(SmallUidError::BeforeEpoch)
+        ));
+        assert!(matches!(
+            DateTime::<Utc>::from_unix_millis(u64::MAX),
+            Err(SmallUidError::TimestampLimit)
+        ));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() {
+        use time::OffsetDateTime;
+
+        let uid = SmallUid::new();
+        let datetime: OffsetDateTime = uid.to_datetime();
+        let from_datetime = SmallUid::from_datetime(datetime).unwrap();
+        assert_eq!(from_datetime.get_timestamp(), uid.get_timestamp());
+
+        let beyond =
+            OffsetDateTime::from_unix_timestamp_nanos((MAX_TIMESTAMP as i128 + 1) * 1_000_000)
+                .unwrap();
+        assert!(matches!(
+            SmallUid::from_datetime(beyond),
+            Err(SmallUidError::TimestampLimit)
+        ));
+        let before = OffsetDateTime::from_unix_timestamp_nanos(-1).unwrap();
+        assert!(matches!(
+            SmallUid::from_datetime(before),
+            Err(SmallUidError::BeforeEpoch)
+        ));
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff() {
+        use jiff::Timestamp;
+
+        let uid = SmallUid::new();
+        let timestamp: Timestamp = uid.to_datetime();
+        let from_timestamp = SmallUid::from_datetime(timestamp).unwrap();
+        assert_eq!(from_timestamp.get_timestamp(), uid.get_timestamp());
+
+        let beyond = Timestamp::from_millisecond(MAX_TIMESTAMP as i64 + 1).unwrap();
+        assert!(matches!(
+            SmallUid::from_datetime(beyond),
+            Err(SmallUidError::TimestampLimit)
+        ));
+        let before = Timestamp::from_millisecond(-1).unwrap();
+        assert!(matches!(
+            SmallUid::from_datetime(before),
+            Err(SmallUidError::BeforeEpoch)
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+//! Diesel `AsExpression`, `ToSql` and `FromSql` implementations for SQLite, Postgres and MySQL.
+//!
+//! A SmallUid is stored as a 64-bit integer that sorts like the SmallUid, with the same
+//! encoding as the [`sqlx`](crate::sqlx) implementations, so `ORDER BY` and the `between`
+//! helpers below follow the SmallUid order:
+//!
+//! | Backend  | SQL type           | Value                         |
+//! | -------- | ------------------ | ----------------------------- |
+//! | Postgres | `BigInt`           | [`SmallUid::to_i64_sortable`] |
+//! | SQLite   | `BigInt`           | [`SmallUid::to_i64_sortable`] |
+//! | MySQL    | `Unsigned<BigInt>` | the raw `u64`                 |
+//!
+//! It also maps to a `Text` column holding its base64-url string, which does not sort like
+//! the SmallUid.
+//!
+//! ```
+//! use diesel::prelude::*;
+//! use small_uid::SmallUid;
+//!
+//! diesel::table! {
+//!     events (id) {
+//!         id -> BigInt,
+//!         parent -> Nullable<Text>,
+//!     }
+//! }
+//!
+//! #[derive(Queryable, Insertable)]
+//! #[diesel(table_name = events)]
+//! struct Event {
+//!     id: SmallUid,
+//!     parent: Option<SmallUid>,
+//! }
+//!
+//! // Events generated during the first hour of 2024
+//! let query = events::table.filter(
+//!     small_uid::diesel::between_timestamps(events::id, 1_704_067_200_000..1_704_070_800_000)
+//!         .unwrap(),
+//! );
+//! ```
+
+use std::ops::RangeBounds;
+
+use ::diesel::{
+    ExpressionMethods,
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    dsl,
+    expression::{AsExpression, Expression},
+    mysql::Mysql,
+    pg::Pg,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{BigInt, Nullable, SingleValue, SqlType, Text, Unsigned},
+    sqlite::Sqlite,
+};
+
+use crate::{Error, SmallUid, SmallUidRange, datetime::SmallUidTime};
+
+/// Implements `AsExpression` for SmallUid and &SmallUid by converting to `$value`.
+macro_rules! impl_as_expression {
+    ($sql_type:ty, $value:ty, $convert:expr) => {
+        impl AsExpression<$sql_type> for SmallUid {
+            type Expression = <$value as AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                <$value as AsExpression<$sql_type>>::as_expression($convert(self))
+            }
+        }
+
+        impl AsExpression<$sql_type> for &SmallUid {
+            type Expression = <$value as AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                <$value as AsExpression<$sql_type>>::as_expression($convert(*self))
+            }
+        }
+    };
+}
+
+impl_as_expression!(BigInt, i64, |uid: SmallUid| uid.to_i64_sortable());
+impl_as_expression!(Nullable<BigInt>, i64, |uid: SmallUid| uid.to_i64_sortable());
+impl_as_expression!(Text, String, |uid: SmallUid| uid.to_string());
+impl_as_expression!(Nullable<Text>, String, |uid: SmallUid| uid.to_string());
+impl_as_expression!(Unsigned<BigInt>, u64, |uid: SmallUid| uid.0);
+impl_as_expression!(Nullable<Unsigned<BigInt>>, u64, |uid: SmallUid| uid.0);
+
+impl ToSql<BigInt, Sqlite> for SmallUid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_i64_sortable());
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Text, Sqlite> for SmallUid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(IsNull::No)
+    }
+}
+
+/// Implements `ToSql` for `Text` on a backend that writes the value into a buffer.
+macro_rules! impl_text_to_sql {
+    ($db:ty) => {
+        impl ToSql<Text, $db> for SmallUid {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $db>) -> serialize::Result {
+                <str as ToSql<Text, $db>>::to_sql(&self.to_string(), &mut out.reborrow())
+            }
+        }
+    };
+}
+
+impl_text_to_sql!(Pg);
+impl_text_to_sql!(Mysql);
+
+impl ToSql<BigInt, Pg> for SmallUid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <i64 as ToSql<BigInt, Pg>>::to_sql(&self.to_i64_sortable(), &mut out.reborrow())
+    }
+}
+
+impl ToSql<Unsigned<BigInt>, Mysql> for SmallUid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        <u64 as ToSql<Unsigned<BigInt>, Mysql>>::to_sql(&self.0, &mut out.reborrow())
+    }
+}
+
+/// Implements `FromSql` for `BigInt` on a signed backend, through [`SmallUid::from_i64_sortable`].
+macro_rules! impl_big_int_from_sql {
+    ($db:ty) => {
+        impl FromSql<BigInt, $db> for SmallUid {
+            fn from_sql(bytes: <$db as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+                <i64 as FromSql<BigInt, $db>>::from_sql(bytes).map(SmallUid::from_i64_sortable)
+            }
+        }
+    };
+}
+
+impl_big_int_from_sql!(Sqlite);
+impl_big_int_from_sql!(Pg);
+
+impl FromSql<Unsigned<BigInt>, Mysql> for SmallUid {
+    fn from_sql(bytes: <Mysql as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        <u64 as FromSql<Unsigned<BigInt>, Mysql>>::from_sql(bytes).map(SmallUid)
+    }
+}
+
+impl<DB: Backend> FromSql<Text, DB> for SmallUid
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(SmallUid::try_from(String::from_sql(bytes)?)?)
+    }
+}
+
+impl<ST: SingleValue, DB: Backend> Queryable<ST, DB> for SmallUid
+where
+    SmallUid: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+/// The SQL types of the columns a SmallUid sorts in: `BigInt`, and `Unsigned<BigInt>` on MySQL.
+pub trait SortableSqlType: SqlType + SingleValue + sealed::Sealed {}
+
+impl SortableSqlType for BigInt {}
+impl SortableSqlType for Unsigned<BigInt> {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::BigInt {}
+    impl Sealed for super::Unsigned<super::BigInt> {}
+}
+
+/// Filters a sortable SmallUid column on the ids of the range, as `column BETWEEN start AND end`.
+pub fn between<C, ST>(column: C, range: SmallUidRange) -> dsl::Between<C, SmallUid, SmallUid>
+where
+    C: Expression<SqlType = ST>,
+    ST: SortableSqlType,
+    SmallUid: AsExpression<ST>,
+{
+    column.between(range.start(), range.end())
+}
+
+/// Filters a sortable SmallUid column on the ids generated within the range of timestamps
+/// in milliseconds, see [`SmallUidRange::from_timestamps`].
+pub fn between_timestamps<C, ST, R>(
+    column: C,
+    timestamps: R,
+) -> Result<dsl::Between<C, SmallUid, SmallUid>, Error>
+where
+    C: Expression<SqlType = ST>,
+    ST: SortableSqlType,
+    SmallUid: AsExpression<ST>,
+    R: RangeBounds<u64>,
+{
+    Ok(between(column, SmallUidRange::from_timestamps(timestamps)?))
+}
+
+/// Filters a sortable SmallUid column on the ids generated within the range of date and
+/// times, see [`SmallUidRange::from_datetimes`].
+pub fn between_datetimes<C, ST, T, R>(
+    column: C,
+    datetimes: R,
+) -> Result<dsl::Between<C, SmallUid, SmallUid>, Error>
+where
+    C: Expression<SqlType = ST>,
+    ST: SortableSqlType,
+    SmallUid: AsExpression<ST>,
+    T: SmallUidTime,
+    R: RangeBounds<T>,
+{
+    Ok(between(column, SmallUidRange::from_datetimes(datetimes)?))
+}
@@ -0,0 +1,153 @@
+use std::{thread::sleep, time::Duration};
+
+use rand::Rng;
+
+use crate::{
+    Error, SmallUid,
+    batch::BatchState,
+    checking::MAX_RANDOM,
+    iter::{Generator, Iter, TryIter},
+};
+
+#[cfg(feature = "stream")]
+use crate::stream::{GeneratorStream, TryGeneratorStream};
+
+/// Generator of random SmallUids that never collide within the same millisecond.
+///
+/// Ids keep their random suffix, but up to 1,048,576 ids per millisecond are guaranteed unique.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DistinctGenerator {
+    pub(crate) state: BatchState,
+}
+
+impl DistinctGenerator {
+    pub fn generate(&mut self) -> SmallUid {
+        generate(self).unwrap()
+    }
+
+    /// Generates a distinct SmallUid, returning `RandomSpaceLimit` instead of waiting
+    /// when every random number of the current millisecond is used.
+    pub fn try_generate(&mut self) -> Result<SmallUid, Error> {
+        try_generate(self)
+    }
+
+    /// Returns an infinite iterator of distinct SmallUids.
+    pub fn iter(&mut self) -> Iter<'_, Self> {
+        Iter::new(self)
+    }
+
+    /// Returns an infinite iterator of distinct SmallUids that yields random space exhaustion
+    /// and clock errors instead of waiting or panicking.
+    pub fn try_iter(&mut self) -> TryIter<'_, Self> {
+        TryIter::new(self)
+    }
+
+    /// Returns an infinite stream of distinct SmallUids.
+    #[cfg(feature = "stream")]
+    pub fn stream(&mut self) -> GeneratorStream<'_, Self> {
+        GeneratorStream::new(self)
+    }
+
+    /// Returns an infinite stream of distinct SmallUids that yields exhaustion
+    /// and clock errors instead of waiting or panicking.
+    #[cfg(feature = "stream")]
+    pub fn try_stream(&mut self) -> TryGeneratorStream<'_, Self> {
+        TryGeneratorStream::new(self)
+    }
+
+    /// Generates `count` distinct SmallUids, reading the clock once per chunk of ids
+    /// instead of once per id.
+    pub fn generate_batch(&mut self, count: usize) -> Vec<SmallUid> {
+        let mut smalluids = vec![SmallUid::default(); count];
+        self.state.fill(&mut smalluids).unwrap();
+        smalluids
+    }
+}
+
+/// Generates a distinct SmallUid without waiting for the next millisecond
+pub fn try_generate(generator: &mut DistinctGenerator) -> Result<SmallUid, Error> {
+    generator.state.tick()?;
+    if generator.state.is_exhausted() {
+        return Err(Error::RandomSpaceLimit);
+    }
+    let mut rng = rand::rng();
+    // 2^20 divides 2^32, so masking keeps the distribution uniform
+    Ok(generator
+        .state
+        .draw(|| rng.random::<u32>() & MAX_RANDOM as u32))
+}
+
+/// Generates a distinct SmallUid
+pub fn generate(generator: &mut DistinctGenerator) -> Result<SmallUid, Error> {
+    loop {
+        match try_generate(generator) {
+            Err(Error::RandomSpaceLimit) => {
+                // Delay until the next millisecond
+                sleep(Duration::from_millis(1));
+            }
+            result => return result,
+        }
+    }
+}
+
+impl Generator for DistinctGenerator {
+    type Item = SmallUid;
+
+    fn generate(&mut self) -> SmallUid {
+        DistinctGenerator::generate(self)
+    }
+
+    fn try_generate(&mut self) -> Result<SmallUid, Error> {
+        DistinctGenerator::try_generate(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checking::MAX_TIMESTAMP;
+
+    #[test]
+    fn test_exhausts_space_without_duplicates() {
+        let mut generator = DistinctGenerator::default();
+        // Keep every id in the same millisecond, one the clock has not reached yet
+        generator.state.timestamp = MAX_TIMESTAMP;
+        let mut seen = vec![false; MAX_RANDOM as usize + 1];
+        for _ in 0..=MAX_RANDOM {
+            let uid = generator.try_generate().unwrap();
+            assert_eq!(uid.get_timestamp(), MAX_TIMESTAMP);
+            let random = uid.get_random() as usize;
+            assert!(!seen[random], "Duplicate random value {}", random);
+            seen[random] = true;
+        }
+        assert!(matches!(
+            generator.try_generate(),
+            Err(Error::RandomSpaceLimit)
+        ));
+    }
+
+    #[test]
+    fn test_generate_batch_uniqueness() {
+        let mut generator = DistinctGenerator::default();
+        let uids = generator.generate_batch(4096);
+        let mut seen = std::collections::HashSet::new();
+        for uid in uids.iter() {
+            assert!(seen.insert(uid), "Duplicate UID found in batch");
+        }
+    }
+
+    #[test]
+    fn test_generate_and_batch_share_the_millisecond() {
+        let mut generator = DistinctGenerator::default();
+        generator.state.timestamp = MAX_TIMESTAMP;
+        let mut seen = std::collections::HashSet::new();
+        assert!(seen.insert(generator.generate()));
+        for uid in generator.generate_batch(4096) {
+            assert_eq!(uid.get_timestamp(), MAX_TIMESTAMP);
+            assert!(
+                seen.insert(uid),
+                "Duplicate random value in one millisecond"
+            );
+        }
+    }
+}
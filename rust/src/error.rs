@@ -17,6 +17,12 @@ pub enum SmallUidError {
     VecToArray,
     InvalidChar,
     MonotonicCounterLimit,
+    RandomSpaceLimit,
+    InvalidRange,
+    NotAUuidV7,
+    I64Range,
+    JsSafeLimit,
+    BeforeEpoch,
 }
 
 impl From<SystemTimeError> for SmallUidError {
@@ -37,6 +43,17 @@ impl From<DecodeSliceError> for SmallUidError {
     }
 }
 
+impl SmallUidError {
+    /// Returns true if the error means the current millisecond is exhausted,
+    /// generating again in the next millisecond will succeed.
+    pub fn is_exhausted(&self) -> bool {
+        matches!(
+            self,
+            SmallUidError::MonotonicCounterLimit | SmallUidError::RandomSpaceLimit
+        )
+    }
+}
+
 impl std::error::Error for SmallUidError {}
 
 impl std::fmt::Display for SmallUidError {
@@ -56,6 +73,16 @@ impl std::fmt::Display for SmallUidError {
             SmallUidError::MonotonicCounterLimit => {
                 f.write_str("MonotonicCounterLimit: Monotonic counter limit reached")
             }
+            SmallUidError::RandomSpaceLimit => {
+                f.write_str("RandomSpaceLimit: Every random number of this millisecond is used")
+            }
+            SmallUidError::InvalidRange => {
+                f.write_str("InvalidRange: Range is empty or its start is after its end")
+            }
+            SmallUidError::NotAUuidV7 => f.write_str("NotAUuidV7: Not a version 7 UUID"),
+            SmallUidError::I64Range => f.write_str("I64Range: Value does not fit in an i64"),
+            SmallUidError::JsSafeLimit => f.write_str("JsSafeLimit: Value does not fit in 53 bits"),
+            SmallUidError::BeforeEpoch => f.write_str("BeforeEpoch: Time is before the unix epoch"),
         }
     }
 }
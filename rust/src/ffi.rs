@@ -0,0 +1,370 @@
+//! `extern "C"` functions over SmallUid and the monotonic generator.
+//!
+//! Ids cross the boundary as plain `uint64_t`, generators as opaque pointers owned by the
+//! caller. Every fallible function returns a `SmallUidStatus` and writes its result through an
+//! out pointer, panics never cross the boundary. The header in `include/small_uid.h` is
+//! generated from this file with `cbindgen --config cbindgen.toml --output include/small_uid.h`.
+
+use std::{
+    ffi::{CStr, c_char, c_int},
+    thread::sleep,
+    time::Duration,
+};
+
+use crate::{MonotonicGenerator, SmallUid, SmallUidError, generation::generate};
+
+/// Size of the buffer `small_uid_format` needs: 11 base64-url characters and a NUL.
+pub const SMALL_UID_STRING_SIZE: usize = 12;
+
+/// Status returned by the C functions, mirrors `SmallUidError` plus errors specific to the FFI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmallUidStatus {
+    Ok = 0,
+    SystemTime = 1,
+    TimestampLimit = 2,
+    RandomSizeLimit = 3,
+    NotABase64Url = 4,
+    DecodeSlice = 5,
+    VecToArray = 6,
+    InvalidChar = 7,
+    MonotonicCounterLimit = 8,
+    RandomSpaceLimit = 9,
+    InvalidRange = 10,
+    NotAUuidV7 = 11,
+    I64Range = 12,
+    JsSafeLimit = 13,
+    BeforeEpoch = 14,
+    /// A pointer argument was NULL.
+    NullPointer = 100,
+    /// The output buffer is smaller than `SMALL_UID_STRING_SIZE`.
+    BufferTooSmall = 101,
+    /// The input string is not valid UTF-8.
+    InvalidUtf8 = 102,
+}
+
+impl SmallUidStatus {
+    /// Every status, integers from C are looked up in it.
+    const ALL: [SmallUidStatus; 18] = [
+        SmallUidStatus::Ok,
+        SmallUidStatus::SystemTime,
+        SmallUidStatus::TimestampLimit,
+        SmallUidStatus::RandomSizeLimit,
+        SmallUidStatus::NotABase64Url,
+        SmallUidStatus::DecodeSlice,
+        SmallUidStatus::VecToArray,
+        SmallUidStatus::InvalidChar,
+        SmallUidStatus::MonotonicCounterLimit,
+        SmallUidStatus::RandomSpaceLimit,
+        SmallUidStatus::InvalidRange,
+        SmallUidStatus::NotAUuidV7,
+        SmallUidStatus::I64Range,
+        SmallUidStatus::JsSafeLimit,
+        SmallUidStatus::BeforeEpoch,
+        SmallUidStatus::NullPointer,
+        SmallUidStatus::BufferTooSmall,
+        SmallUidStatus::InvalidUtf8,
+    ];
+
+    fn from_code(code: c_int) -> Option<SmallUidStatus> {
+        SmallUidStatus::ALL
+            .into_iter()
+            .find(|status| *status as c_int == code)
+    }
+
+    /// Description of the status, the `Display` of the matching `SmallUidError` when it has
+    /// no inner error.
+    fn message(self) -> &'static CStr {
+        match self {
+            SmallUidStatus::Ok => c"Ok",
+            SmallUidStatus::SystemTime => c"SystemTime: Failed to read the system clock",
+            SmallUidStatus::TimestampLimit => {
+                c"TimestampLimit: Timestamp too large. Is it year 2528?"
+            }
+            SmallUidStatus::RandomSizeLimit => c"RandomSizeLimit: Random number too large. How?",
+            SmallUidStatus::NotABase64Url => c"NotABase64Url: Not a base64url string",
+            SmallUidStatus::DecodeSlice => c"DecodeSlice: Decoded value is not 8 bytes",
+            SmallUidStatus::VecToArray => c"VecToArray: Failed to convert",
+            SmallUidStatus::InvalidChar => c"InvalidChar: Invalid character",
+            SmallUidStatus::MonotonicCounterLimit => {
+                c"MonotonicCounterLimit: Monotonic counter limit reached"
+            }
+            SmallUidStatus::RandomSpaceLimit => {
+                c"RandomSpaceLimit: Every random number of this millisecond is used"
+            }
+            SmallUidStatus::InvalidRange => {
+                c"InvalidRange: Range is empty or its start is after its end"
+            }
+            SmallUidStatus::NotAUuidV7 => c"NotAUuidV7: Not a version 7 UUID",
+            SmallUidStatus::I64Range => c"I64Range: Value does not fit in an i64",
+            SmallUidStatus::JsSafeLimit => c"JsSafeLimit: Value does not fit in 53 bits",
+            SmallUidStatus::BeforeEpoch => c"BeforeEpoch: Time is before the unix epoch",
+            SmallUidStatus::NullPointer => c"NullPointer: A pointer argument is NULL",
+            SmallUidStatus::BufferTooSmall => c"BufferTooSmall: Buffer is smaller than 12 bytes",
+            SmallUidStatus::InvalidUtf8 => c"InvalidUtf8: String is not valid UTF-8",
+        }
+    }
+}
+
+impl From<&SmallUidError> for SmallUidStatus {
+    fn from(err: &SmallUidError) -> Self {
+        match err {
+            SmallUidError::SystemTime(_) => SmallUidStatus::SystemTime,
+            SmallUidError::TimestampLimit => SmallUidStatus::TimestampLimit,
+            SmallUidError::RandomSizeLimit => SmallUidStatus::RandomSizeLimit,
+            SmallUidError::NotABase64Url => SmallUidStatus::NotABase64Url,
+            SmallUidError::DecodeSlice(_) => SmallUidStatus::DecodeSlice,
+            SmallUidError::VecToArray => SmallUidStatus::VecToArray,
+            SmallUidError::InvalidChar => SmallUidStatus::InvalidChar,
+            SmallUidError::MonotonicCounterLimit => SmallUidStatus::MonotonicCounterLimit,
+            SmallUidError::RandomSpaceLimit => SmallUidStatus::RandomSpaceLimit,
+            SmallUidError::InvalidRange => SmallUidStatus::InvalidRange,
+            SmallUidError::NotAUuidV7 => SmallUidStatus::NotAUuidV7,
+            SmallUidError::I64Range => SmallUidStatus::I64Range,
+            SmallUidError::JsSafeLimit => SmallUidStatus::JsSafeLimit,
+            SmallUidError::BeforeEpoch => SmallUidStatus::BeforeEpoch,
+        }
+    }
+}
+
+impl From<SmallUidError> for SmallUidStatus {
+    fn from(err: SmallUidError) -> Self {
+        SmallUidStatus::from(&err)
+    }
+}
+
+/// Writes the result through the out pointer, or returns `NullPointer`.
+fn write_out(out: *mut u64, result: Result<SmallUid, SmallUidError>) -> SmallUidStatus {
+    if out.is_null() {
+        return SmallUidStatus::NullPointer;
+    }
+    match result {
+        Ok(uid) => {
+            // SAFETY: checked for NULL above, the caller guarantees it is valid for writes.
+            unsafe { out.write(uid.to_u64()) };
+            SmallUidStatus::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Returns a static, NUL-terminated description of a status, "Unknown status" for any other
+/// integer.
+#[unsafe(no_mangle)]
+pub extern "C" fn small_uid_status_message(status: c_int) -> *const c_char {
+    SmallUidStatus::from_code(status)
+        .map_or(c"Unknown status", SmallUidStatus::message)
+        .as_ptr()
+}
+
+/// Generates a new SmallUid into `out`.
+///
+/// # Safety
+///
+/// `out` must be NULL or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn small_uid_new(out: *mut u64) -> SmallUidStatus {
+    write_out(out, generate())
+}
+
+/// Parses a NUL-terminated base64-url string into `out`.
+///
+/// # Safety
+///
+/// `string` must be NULL or point to a NUL-terminated string, `out` must be NULL or valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn small_uid_parse(string: *const c_char, out: *mut u64) -> SmallUidStatus {
+    if string.is_null() {
+        return SmallUidStatus::NullPointer;
+    }
+    // SAFETY: checked for NULL above, the caller guarantees it is NUL-terminated.
+    let string = match unsafe { CStr::from_ptr(string) }.to_str() {
+        Ok(string) => string,
+        Err(_) => return SmallUidStatus::InvalidUtf8,
+    };
+    write_out(out, SmallUid::try_from(string.to_string()))
+}
+
+/// Writes the base64-url encoding of `id` and a terminating NUL into `buffer`.
+///
+/// `size` is the size of the buffer, at least `SMALL_UID_STRING_SIZE`.
+///
+/// # Safety
+///
+/// `buffer` must be NULL or valid for writes of `size` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn small_uid_format(
+    id: u64,
+    buffer: *mut c_char,
+    size: usize,
+) -> SmallUidStatus {
+    if buffer.is_null() {
+        return SmallUidStatus::NullPointer;
+    }
+    if size < SMALL_UID_STRING_SIZE {
+        return SmallUidStatus::BufferTooSmall;
+    }
+    let string = SmallUid(id).to_string();
+    // SAFETY: checked for NULL above, the encoding is 11 bytes and the buffer holds at least 12.
+    unsafe {
+        buffer.copy_from_nonoverlapping(string.as_ptr().cast(), string.len());
+        buffer.add(string.len()).write(0);
+    }
+    SmallUidStatus::Ok
+}
+
+/// Returns the timestamp of `id` in milliseconds since the unix epoch.
+#[unsafe(no_mangle)]
+pub extern "C" fn small_uid_timestamp(id: u64) -> u64 {
+    SmallUid(id).get_timestamp()
+}
+
+/// Returns the 20 random bits of `id`.
+#[unsafe(no_mangle)]
+pub extern "C" fn small_uid_random(id: u64) -> u64 {
+    SmallUid(id).get_random()
+}
+
+/// Creates a monotonic generator, to be released with `small_uid_monotonic_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn small_uid_monotonic_new() -> *mut MonotonicGenerator {
+    Box::into_raw(Box::new(SmallUid::init_monotonic()))
+}
+
+/// Generates the next monotonic SmallUid into `out`, waiting for the next millisecond
+/// when the counter is exhausted.
+///
+/// # Safety
+///
+/// `generator` must be NULL or come from `small_uid_monotonic_new` and not be used by
+/// another thread at the same time, `out` must be NULL or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn small_uid_monotonic_generate(
+    generator: *mut MonotonicGenerator,
+    out: *mut u64,
+) -> SmallUidStatus {
+    // SAFETY: the caller guarantees it is NULL or a live generator used by this thread only.
+    let Some(generator) = (unsafe { generator.as_mut() }) else {
+        return SmallUidStatus::NullPointer;
+    };
+    loop {
+        match generator.try_generate() {
+            Err(err) if err.is_exhausted() => {
+                // Delay until the next millisecond
+                sleep(Duration::from_millis(1));
+            }
+            result => return write_out(out, result),
+        }
+    }
+}
+
+/// Releases a generator created by `small_uid_monotonic_new`, NULL is ignored.
+///
+/// # Safety
+///
+/// `generator` must be NULL or come from `small_uid_monotonic_new` and not be freed twice.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn small_uid_monotonic_free(generator: *mut MonotonicGenerator) {
+    if !generator.is_null() {
+        // SAFETY: the caller guarantees it comes from `Box::into_raw` and is freed once.
+        drop(unsafe { Box::from_raw(generator) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn test_format_parse_round_trip() {
+        let mut id = 0;
+        assert_eq!(unsafe { small_uid_new(&mut id) }, SmallUidStatus::Ok);
+        assert!(small_uid_timestamp(id) > 0);
+
+        let mut buffer = [1 as c_char; SMALL_UID_STRING_SIZE];
+        assert_eq!(
+            unsafe { small_uid_format(id, buffer.as_mut_ptr(), buffer.len()) },
+            SmallUidStatus::Ok
+        );
+        let string = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(string.to_str().unwrap(), SmallUid(id).to_string());
+
+        let mut parsed = 0;
+        assert_eq!(
+            unsafe { small_uid_parse(buffer.as_ptr(), &mut parsed) },
+            SmallUidStatus::Ok
+        );
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut id = 0;
+        assert_eq!(
+            unsafe { small_uid_new(ptr::null_mut()) },
+            SmallUidStatus::NullPointer
+        );
+        assert_eq!(
+            unsafe { small_uid_parse(c"short".as_ptr(), &mut id) },
+            SmallUidStatus::NotABase64Url
+        );
+        assert_eq!(
+            unsafe { small_uid_parse(ptr::null(), &mut id) },
+            SmallUidStatus::NullPointer
+        );
+        let mut buffer = [0 as c_char; SMALL_UID_STRING_SIZE - 1];
+        assert_eq!(
+            unsafe { small_uid_format(0, buffer.as_mut_ptr(), buffer.len()) },
+            SmallUidStatus::BufferTooSmall
+        );
+        let message = small_uid_status_message(SmallUidStatus::NotABase64Url as c_int);
+        let message = unsafe { CStr::from_ptr(message) };
+        assert!(message.to_str().unwrap().starts_with("NotABase64Url"));
+        let message = unsafe { CStr::from_ptr(small_uid_status_message(42)) };
+        assert_eq!(message, c"Unknown status");
+    }
+
+    #[test]
+    fn test_status_messages_match_errors() {
+        let errors = [
+            SmallUidError::TimestampLimit,
+            SmallUidError::RandomSizeLimit,
+            SmallUidError::NotABase64Url,
+            SmallUidError::VecToArray,
+            SmallUidError::InvalidChar,
+            SmallUidError::MonotonicCounterLimit,
+            SmallUidError::RandomSpaceLimit,
+            SmallUidError::InvalidRange,
+            SmallUidError::NotAUuidV7,
+            SmallUidError::I64Range,
+            SmallUidError::JsSafeLimit,
+            SmallUidError::BeforeEpoch,
+        ];
+        for err in errors {
+            let status = SmallUidStatus::from(&err);
+            assert_eq!(status.message().to_str().unwrap(), err.to_string());
+        }
+        for status in SmallUidStatus::ALL {
+            assert_eq!(SmallUidStatus::from_code(status as c_int), Some(status));
+        }
+    }
+
+    #[test]
+    fn test_monotonic() {
+        let generator = small_uid_monotonic_new();
+        let mut previous = 0;
+        for _ in 0..2048 {
+            let mut id = 0;
+            assert_eq!(
+                unsafe { small_uid_monotonic_generate(generator, &mut id) },
+                SmallUidStatus::Ok
+            );
+            assert!(id > previous);
+            previous = id;
+        }
+        unsafe { small_uid_monotonic_free(generator) };
+        unsafe { small_uid_monotonic_free(ptr::null_mut()) };
+    }
+}
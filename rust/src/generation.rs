@@ -1,4 +1,4 @@
-use crate::{Error, SmallUid};
+use crate::{Error, SmallUid, checking::timestamp_check};
 use rand::Rng;
 
 #[cfg(target_arch = "wasm32")]
@@ -22,12 +22,17 @@ pub fn random_gen() -> u64 {
 
 /// Generates SmallUid using timestamp_gen() and random_gen()
 pub fn generate() -> Result<SmallUid, Error> {
-    let timestamp = timestamp_gen()?;
+    let timestamp = timestamp_check(timestamp_gen()?)?;
     let random = random_gen();
     Ok(assemble(timestamp, random))
 }
 
-pub fn assemble(timestamp: u64, random: u64) -> SmallUid {
+/// Assembles a SmallUid without checking the timestamp.
+///
+/// Callers must make sure the timestamp went through `timestamp_check`,
+/// otherwise its high bits are shifted out and the id wraps to the past.
+pub const fn assemble(timestamp: u64, random: u64) -> SmallUid {
+    debug_assert!(timestamp >> 44 == 0, "timestamp exceeds 44 bits");
     let timestamp = timestamp << 20;
 
     // CHANGE: Using a bitmask to ensure that we only use the lower 20 bits
@@ -0,0 +1,86 @@
+//! gRPC `SmallUidService` implemented with tonic over a shared monotonic generator.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), tonic::transport::Error> {
+//! use small_uid::grpc::{SmallUidGrpcService, SmallUidServiceServer};
+//!
+//! tonic::transport::Server::builder()
+//!     .add_service(SmallUidServiceServer::new(SmallUidGrpcService::default()))
+//!     .serve("127.0.0.1:50051".parse().unwrap())
+//!     .await
+//! # }
+//! ```
+
+use tonic::{Request, Response, Status};
+
+use crate::{
+    SmallUidError,
+    proto::{
+        DecodeRequest, DecodeResponse, NextBatchRequest, NextBatchResponse, NextRequest, SmallUid,
+        decode_request, small_uid_service_server::SmallUidService,
+    },
+    shared::SharedGenerator,
+};
+
+pub use crate::proto::{
+    small_uid_service_client::SmallUidServiceClient,
+    small_uid_service_server::SmallUidServiceServer,
+};
+pub use crate::shared::MAX_BATCH;
+
+/// Implementation of `SmallUidService`, all clients get globally ordered ids.
+#[derive(Debug, Default)]
+pub struct SmallUidGrpcService {
+    generator: SharedGenerator,
+}
+
+/// Parse errors come from the request, the other errors from the server or its clock.
+impl From<SmallUidError> for Status {
+    fn from(err: SmallUidError) -> Self {
+        match err {
+            SmallUidError::NotABase64Url
+            | SmallUidError::DecodeSlice(_)
+            | SmallUidError::VecToArray
+            | SmallUidError::InvalidChar => Status::invalid_argument(err.to_string()),
+            _ if err.is_exhausted() => Status::unavailable(err.to_string()),
+            _ => Status::internal(err.to_string()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl SmallUidService for SmallUidGrpcService {
+    async fn next(&self, _request: Request<NextRequest>) -> Result<Response<SmallUid>, Status> {
+        let uids = self.generator.generate(1).await?;
+        Ok(Response::new(uids[0].into()))
+    }
+
+    async fn next_batch(
+        &self,
+        request: Request<NextBatchRequest>,
+    ) -> Result<Response<NextBatchResponse>, Status> {
+        let count = request.into_inner().count as usize;
+        if count == 0 || count > MAX_BATCH {
+            return Err(Status::invalid_argument(format!(
+                "count must be between 1 and {}",
+                MAX_BATCH
+            )));
+        }
+        let uids = self.generator.generate(count).await?;
+        Ok(Response::new(NextBatchResponse {
+            ids: uids.into_iter().map(SmallUid::from).collect(),
+        }))
+    }
+
+    async fn decode(
+        &self,
+        request: Request<DecodeRequest>,
+    ) -> Result<Response<DecodeResponse>, Status> {
+        let uid = match request.into_inner().id {
+            Some(decode_request::Id::Uid(uid)) => crate::SmallUid::from(uid),
+            Some(decode_request::Id::Text(text)) => crate::SmallUid::try_from(text)?,
+            None => return Err(Status::invalid_argument("id is missing")),
+        };
+        Ok(Response::new(uid.inspect().into()))
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::SmallUid;
+
+/// Human-readable explanation of a SmallUid, returned by [`SmallUid::inspect`].
+///
+/// It prints as a multi-line report and, with the `serde` feature, serializes as a flat record
+/// so debugging tools, the CLI and admin UIs share one explanation of an id.
+///
+/// ```
+/// use small_uid::SmallUid;
+///
+/// let info = SmallUid::from_parts(1_700_000_000_123, 0x5903C).inspect();
+/// assert_eq!(info.utc, "2023-11-14T22:13:20.123Z");
+/// assert_eq!((info.monotonic_counter, info.monotonic_random), (356, 60));
+/// println!("{}", info);
+/// ```
+#[derive(Hash, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmallUidInfo {
+    /// The SmallUid itself.
+    pub id: SmallUid,
+    /// The raw 64-bit value, serialized as a decimal string.
+    #[cfg_attr(feature = "serde", serde(with = "decimal"))]
+    pub value: u64,
+    /// The value as 16 hexadecimal digits.
+    pub hex: String,
+    /// The 44 timestamp bits and the 20 random bits, separated by a space.
+    pub layout: String,
+    /// Milliseconds since the unix epoch.
+    pub timestamp: u64,
+    /// The timestamp as an RFC 3339 UTC date with milliseconds.
+    pub utc: String,
+    /// Milliseconds elapsed since the timestamp when inspected, zero if it is in the future.
+    pub age_ms: u64,
+    /// The 20 random bits.
+    pub random: u64,
+    /// The upper 10 random bits, the counter of a `MonotonicGenerator`.
+    pub monotonic_counter: u16,
+    /// The lower 10 random bits, the per-millisecond random of a `MonotonicGenerator`.
+    pub monotonic_random: u16,
+}
+
+/// Serializes a `u64` as a decimal string, JavaScript numbers lose precision above 2^53.
+#[cfg(feature = "serde")]
+mod decimal {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl SmallUid {
+    /// Explains the SmallUid: its encodings, bit layout, date, age and random parts.
+    pub fn inspect(&self) -> SmallUidInfo {
+        let random = self.get_random();
+        SmallUidInfo {
+            id: *self,
+            value: self.0,
+            hex: format!("{:016x}", self.0),
+            layout: format!("{:044b} {:020b}", self.get_timestamp(), random),
+            timestamp: self.get_timestamp(),
+            utc: rfc3339(self.get_timestamp()),
+            age_ms: self.age().as_millis() as u64,
+            random,
+            monotonic_counter: (random >> 10) as u16,
+            monotonic_random: (random & 0x3FF) as u16,
+        }
+    }
+}
+
+impl Display for SmallUidInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "SmallUid   {}", self.id)?;
+        writeln!(f, "value      {}", self.value)?;
+        writeln!(f, "hex        {}", self.hex)?;
+        writeln!(f, "layout     {} (timestamp | random)", self.layout)?;
+        writeln!(f, "timestamp  {} ms", self.timestamp)?;
+        writeln!(f, "utc        {}", self.utc)?;
+        writeln!(f, "age        {}", age(self.age_ms))?;
+        writeln!(f, "random     {} ({:05x})", self.random, self.random)?;
+        write!(
+            f,
+            "monotonic  counter {}, random {}",
+            self.monotonic_counter, self.monotonic_random
+        )
+    }
+}
+
+/// Formats milliseconds since the unix epoch as `YYYY-MM-DDTHH:MM:SS.mmmZ`.
+fn rfc3339(millis: u64) -> String {
+    let days = millis / 86_400_000;
+    let ms_of_day = millis % 86_400_000;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1_000 % 60,
+        ms_of_day % 1_000
+    )
+}
+
+/// Converts days since the unix epoch to a proleptic Gregorian date,
+/// from Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as u64;
+    (year, month, day)
+}
+
+/// Formats an age as days, hours, minutes and seconds, omitting the leading zero units.
+fn age(millis: u64) -> String {
+    let (days, hours, minutes) = (
+        millis / 86_400_000,
+        millis / 3_600_000 % 24,
+        millis / 60_000 % 60,
+    );
+    let seconds = format!("{}.{:03}s", millis / 1_000 % 60, millis % 1_000);
+    match (days, hours, minutes) {
+        (0, 0, 0) => seconds,
+        (0, 0, _) => format!("{}m {}", minutes, seconds),
+        (0, _, _) => format!("{}h {}m {}", hours, minutes, seconds),
+        _ => format!("{}d {}h {}m {}", days, hours, minutes, seconds),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checking::{MAX_RANDOM, MAX_TIMESTAMP};
+
+    #[test]
+    fn test_rfc3339() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(rfc3339(951_782_400_000), "2000-02-29T00:00:00.000Z");
+        assert_eq!(rfc3339(1_700_000_000_123), "2023-11-14T22:13:20.123Z");
+        assert_eq!(rfc3339(MAX_TIMESTAMP), "2527-06-23T06:20:44.415Z");
+    }
+
+    #[test]
+    fn test_inspect() {
+        let info = SmallUid::from_parts(1_700_000_000_123, 0x5903C).inspect();
+        assert_eq!(info.value, 1_700_000_000_123 << 20 | 0x5903C);
+        assert_eq!(info.hex, format!("{:016x}", info.value));
+        assert_eq!(info.layout.len(), 65);
+        assert_eq!(
+            u64::from_str_radix(&info.layout.replace(' ', ""), 2).unwrap(),
+            info.value
+        );
+        assert_eq!(info.random, 0x5903C);
+        assert_eq!(info.monotonic_counter, 356);
+        assert_eq!(info.monotonic_random, 60);
+        assert!(info.age_ms > 0);
+
+        let future = SmallUid::from_parts(MAX_TIMESTAMP, MAX_RANDOM).inspect();
+        assert_eq!(future.age_ms, 0);
+        assert_eq!(future.monotonic_counter, 0x3FF);
+    }
+
+    #[test]
+    fn test_display() {
+        let info = SmallUid::from_parts(0, 0).inspect();
+        let text = info.to_string();
+        assert!(text.starts_with("SmallUid   AAAAAAAAAAA\n"));
+        assert!(text.contains("utc        1970-01-01T00:00:00.000Z"));
+        assert_eq!(age(0), "0.000s");
+        assert_eq!(age(61_001), "1m 1.001s");
+        assert_eq!(age(90_061_001), "1d 1h 1m 1.001s");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let info = SmallUid::new().inspect();
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["id"], info.id.to_string());
+        assert_eq!(json["value"], info.value.to_string());
+        assert_eq!(json["utc"], info.utc);
+        let deserialized: SmallUidInfo = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, info);
+    }
+}
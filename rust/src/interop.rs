@@ -0,0 +1,192 @@
+//! Both UUIDv7 and ULID start with a 48-bit millisecond timestamp followed by random bits.
+//!
+//! A SmallUid is embedded by storing its timestamp in that field and its 20 random bits
+//! in the first 20 random bits, every other random bit is zero. Truncating takes the same
+//! bits back, so embedded ids round-trip and the sort order is preserved both ways.
+
+use crate::{Error, SmallUid, checking::timestamp_check, generation::assemble};
+
+#[cfg(feature = "uuid")]
+impl SmallUid {
+    /// Embeds the SmallUid into a UUIDv7.
+    ///
+    /// The timestamp goes into `unix_ts_ms`, the 20 random bits into the 12 bits of `rand_a`
+    /// followed by the first 8 bits of `rand_b`, the remaining bits of `rand_b` are zero.
+    pub fn to_uuid_v7(&self) -> uuid::Uuid {
+        let random = self.get_random() as u128;
+        let rand_a = random >> 8;
+        let rand_b = (random & 0xFF) << 54;
+        let value =
+            (self.get_timestamp() as u128) << 80 | 0x7 << 76 | rand_a << 64 | 0b10 << 62 | rand_b;
+        uuid::Uuid::from_u128(value)
+    }
+
+    /// Extracts a SmallUid from a UUIDv7, the reverse of [`SmallUid::to_uuid_v7`].
+    ///
+    /// Any UUIDv7 is accepted: only the first 20 of its 74 random bits are kept, the other 54 are lost.
+    /// Fails if the UUID is not a version 7 UUID or if its timestamp is beyond the 44-bit range.
+    pub fn from_uuid_v7(uuid: uuid::Uuid) -> Result<SmallUid, Error> {
+        if uuid.get_version_num() != 7 || uuid.get_variant() != uuid::Variant::RFC4122 {
+            return Err(Error::NotAUuidV7);
+        }
+        let value = uuid.as_u128();
+        let timestamp = timestamp_check((value >> 80) as u64)?;
+        let rand_a = (value >> 64) & 0xFFF;
+        let rand_b = (value >> 54) & 0xFF;
+        Ok(assemble(timestamp, (rand_a << 8 | rand_b) as u64))
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<SmallUid> for uuid::Uuid {
+    fn from(value: SmallUid) -> Self {
+        value.to_uuid_v7()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl TryFrom<uuid::Uuid> for SmallUid {
+    type Error = Error;
+
+    fn try_from(value: uuid::Uuid) -> Result<Self, Self::Error> {
+        SmallUid::from_uuid_v7(value)
+    }
+}
+
+#[cfg(feature = "ulid")]
+impl SmallUid {
+    /// Embeds the SmallUid into a ULID.
+    ///
+    /// The 20 random bits are the first 20 of the 80 random bits of the ULID, the others are zero.
+    pub fn to_ulid(&self) -> ulid::Ulid {
+        ulid::Ulid::from_parts(self.get_timestamp(), (self.get_random() as u128) << 60)
+    }
+
+    /// Extracts a SmallUid from a ULID, the reverse of [`SmallUid::to_ulid`].
+    ///
+    /// Any ULID is accepted: only the first 20 of its 80 random bits are kept, the other 60 are lost.
+    /// Fails if the timestamp of the ULID is beyond the 44-bit range.
+    pub fn from_ulid(ulid: ulid::Ulid) -> Result<SmallUid, Error> {
+        let timestamp = timestamp_check(ulid.timestamp_ms())?;
+        Ok(assemble(timestamp, (ulid.random() >> 60) as u64))
+    }
+}
+
+#[cfg(feature = "ulid")]
+impl From<SmallUid> for ulid::Ulid {
+    fn from(value: SmallUid) -> Self {
+        value.to_ulid()
+    }
+}
+
+#[cfg(feature = "ulid")]
+impl TryFrom<ulid::Ulid> for SmallUid {
+    type Error = Error;
+
+    fn try_from(value: ulid::Ulid) -> Result<Self, Self::Error> {
+        SmallUid::from_ulid(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        SmallUidError,
+        checking::{MAX_RANDOM, MAX_TIMESTAMP},
+    };
+
+    fn samples() -> Vec<SmallUid> {
+        let mut samples = vec![
+            SmallUid::from_parts(0, 0),
+            SmallUid::from_parts(0, MAX_RANDOM),
+            SmallUid::from_parts(MAX_TIMESTAMP, 0),
+            SmallUid::from_parts(MAX_TIMESTAMP, MAX_RANDOM),
+            SmallUid::from_parts(1_700_000_000_000, 0x80),
+            SmallUid::from_parts(1_700_000_000_000, 0x7F),
+        ];
+        samples.extend(SmallUid::batch_new(1000));
+        samples
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid_round_trip() {
+        for uid in samples() {
+            let uuid = uid.to_uuid_v7();
+            assert_eq!(uuid.get_version_num(), 7);
+            assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+            assert_eq!(SmallUid::from_uuid_v7(uuid).unwrap(), uid);
+            assert_eq!(SmallUid::try_from(uuid::Uuid::from(uid)).unwrap(), uid);
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid_ordering() {
+        let mut uids = samples();
+        uids.sort();
+        let uuids: Vec<uuid::Uuid> = uids.iter().map(SmallUid::to_uuid_v7).collect();
+        for i in 1..uuids.len() {
+            assert_eq!(uids[i].cmp(&uids[i - 1]), uuids[i].cmp(&uuids[i - 1]));
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid_truncation() {
+        // unix_ts_ms = 1, rand_a = 0xABC, rand_b starts with 0xDE
+        let uuid = uuid::Uuid::from_u128(
+            1 << 80 | 0x7 << 76 | 0xABC << 64 | 0b10 << 62 | 0xDE << 54 | 0x3F_FFFF_FFFF_FFFF,
+        );
+        let uid = SmallUid::from_uuid_v7(uuid).unwrap();
+        assert_eq!(uid.get_timestamp(), 1);
+        assert_eq!(uid.get_random(), 0xABCDE);
+
+        assert!(matches!(
+            SmallUid::from_uuid_v7(uuid::Uuid::nil()),
+            Err(SmallUidError::NotAUuidV7)
+        ));
+        let too_late =
+            uuid::Uuid::from_u128((MAX_TIMESTAMP as u128 + 1) << 80 | 0x7 << 76 | 0b10 << 62);
+        assert!(matches!(
+            SmallUid::from_uuid_v7(too_late),
+            Err(SmallUidError::TimestampLimit)
+        ));
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn test_ulid_round_trip_and_ordering() {
+        let mut uids = samples();
+        uids.sort();
+        let ulids: Vec<ulid::Ulid> = uids.iter().map(SmallUid::to_ulid).collect();
+        for (uid, ulid) in uids.iter().zip(ulids.iter()) {
+            assert_eq!(ulid.timestamp_ms(), uid.get_timestamp());
+            assert_eq!(SmallUid::from_ulid(*ulid).unwrap(), *uid);
+            assert_eq!(SmallUid::try_from(ulid::Ulid::from(*uid)).unwrap(), *uid);
+        }
+        for i in 1..ulids.len() {
+            assert_eq!(uids[i].cmp(&uids[i - 1]), ulids[i].cmp(&ulids[i - 1]));
+            assert_eq!(
+                uids[i].cmp(&uids[i - 1]),
+                ulids[i].to_string().cmp(&ulids[i - 1].to_string())
+            );
+        }
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn test_ulid_truncation() {
+        let ulid = ulid::Ulid::from_parts(42, 0xABCDE << 60 | 0xFFF_FFFF_FFFF_FFFF);
+        let uid = SmallUid::from_ulid(ulid).unwrap();
+        assert_eq!(uid.get_timestamp(), 42);
+        assert_eq!(uid.get_random(), 0xABCDE);
+
+        let too_late = ulid::Ulid::from_parts(MAX_TIMESTAMP + 1, 0);
+        assert!(matches!(
+            SmallUid::from_ulid(too_late),
+            Err(SmallUidError::TimestampLimit)
+        ));
+    }
+}
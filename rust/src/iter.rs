@@ -0,0 +1,142 @@
+use std::iter::FusedIterator;
+
+use crate::Error;
+
+/// Stateful SmallUid generators that can be driven by iterators and streams.
+pub trait Generator {
+    /// Type of the generated ids.
+    type Item;
+
+    /// Generates the next id, waiting for the next millisecond when the current one is exhausted.
+    fn generate(&mut self) -> Self::Item;
+
+    /// Generates the next id, returning an error instead of waiting when the current millisecond is exhausted.
+    fn try_generate(&mut self) -> Result<Self::Item, Error>;
+}
+
+/// Infinite iterator of ids, returned by `iter()` on the generators.
+#[derive(Debug)]
+pub struct Iter<'a, G: Generator> {
+    generator: &'a mut G,
+}
+
+impl<'a, G: Generator> Iter<'a, G> {
+    pub(crate) fn new(generator: &'a mut G) -> Self {
+        Iter { generator }
+    }
+}
+
+impl<G: Generator> Iterator for Iter<'_, G> {
+    type Item = G::Item;
+
+    fn next(&mut self) -> Option<G::Item> {
+        Some(self.generator.generate())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<G: Generator> FusedIterator for Iter<'_, G> {}
+
+/// Infinite iterator of fallible ids, returned by `try_iter()` on the generators.
+///
+/// Exhaustion of the current millisecond and clock errors are yielded as `Err`,
+/// the iterator can be polled again afterward.
+#[derive(Debug)]
+pub struct TryIter<'a, G: Generator> {
+    generator: &'a mut G,
+}
+
+impl<'a, G: Generator> TryIter<'a, G> {
+    pub(crate) fn new(generator: &'a mut G) -> Self {
+        TryIter { generator }
+    }
+}
+
+impl<G: Generator> Iterator for TryIter<'_, G> {
+    type Item = Result<G::Item, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.generator.try_generate())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<G: Generator> FusedIterator for TryIter<'_, G> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SmallUid, SmallUidError, checking::MAX_TIMESTAMP};
+
+    #[test]
+    fn test_iter_take_collect() {
+        let mut generator = SmallUid::init_monotonic();
+        let uids: Vec<SmallUid> = generator.iter().take(2048).collect();
+        assert_eq!(uids.len(), 2048);
+        for i in 1..uids.len() {
+            assert!(
+                uids[i] > uids[i - 1],
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_iter_zip() {
+        let mut generator = SmallUid::init_distinct();
+        let names = ["a", "b", "c"];
+        let pairs: Vec<(&str, SmallUid)> = names.into_iter().zip(generator.iter()).collect();
+        assert_eq!(pairs.len(), 3);
+        assert_ne!(pairs[0].1, pairs[1].1);
+        assert_ne!(pairs[1].1, pairs[2].1);
+    }
+
+    #[test]
+    fn test_try_iter_reports_exhaustion() {
+        let mut generator = SmallUid::init_monotonic();
+        // Pretend the counter of a millisecond in the future is used up
+        generator.last_ms = MAX_TIMESTAMP;
+        generator.upper_counter = 0x3FF;
+        let mut iter = generator.try_iter();
+        assert!(matches!(
+            iter.next(),
+            Some(Err(SmallUidError::MonotonicCounterLimit))
+        ));
+        assert!(matches!(
+            iter.next(),
+            Some(Err(SmallUidError::MonotonicCounterLimit))
+        ));
+
+        let mut generator = SmallUid::init_distinct();
+        // Pretend every random number of a millisecond in the future is used
+        generator.state.timestamp = MAX_TIMESTAMP;
+        generator.state.capacity = 0;
+        assert!(matches!(
+            generator.try_iter().next(),
+            Some(Err(SmallUidError::RandomSpaceLimit))
+        ));
+    }
+
+    #[test]
+    fn test_try_iter_ok_values_are_monotonic() {
+        let mut generator = SmallUid::init_monotonic();
+        let uids: Vec<SmallUid> = generator
+            .try_iter()
+            .take(2048)
+            .filter_map(Result::ok)
+            .collect();
+        for i in 1..uids.len() {
+            assert!(
+                uids[i] > uids[i - 1],
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::{fmt::Display, thread::sleep, time::Duration};
+
+use rand::Rng;
+
+use crate::{
+    Error, SmallUid, SmallUidError,
+    checking::timestamp_check,
+    iter::{Generator, Iter, TryIter},
+    timestamp_gen,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Number of random bits of a JsSafeUid.
+pub const RANDOM_BITS: u32 = 9;
+
+/// Largest random number that fits in the 9-bit random field.
+pub const MAX_RANDOM: u64 = (1 << RANDOM_BITS) - 1;
+
+/// Largest value of a JsSafeUid, `Number.MAX_SAFE_INTEGER` in JavaScript.
+pub const MAX_VALUE: u64 = (1 << 53) - 1;
+
+/// Checks if the random number is within the 9-bit range.
+pub const fn rng_size_check(rn: u64) -> Result<u64, Error> {
+    if rn <= MAX_RANDOM {
+        Ok(rn)
+    } else {
+        Err(SmallUidError::RandomSizeLimit)
+    }
+}
+
+/// Checks if the value fits in 53 bits.
+pub const fn value_check(value: u64) -> Result<u64, Error> {
+    if value <= MAX_VALUE {
+        Ok(value)
+    } else {
+        Err(SmallUidError::JsSafeLimit)
+    }
+}
+
+/// A 53-bit SmallUid that stays exact when parsed as a JavaScript number or an IEEE double.
+///
+/// It keeps the 44-bit millisecond timestamp of SmallUid followed by 9 random bits,
+/// so up to 512 different values per millisecond. Use [`JsSafeUid::init_monotonic`]
+/// when more than a couple of ids per millisecond are generated.
+///
+/// With the `serde` feature it is serialized as a plain integer.
+///
+/// ```
+/// use small_uid::js_safe::JsSafeUid;
+///
+/// let id = JsSafeUid::new();
+/// assert!(id.to_u64() <= small_uid::js_safe::MAX_VALUE);
+/// assert_eq!(id.to_f64() as u64, id.to_u64());
+/// ```
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct JsSafeUid(u64);
+
+impl JsSafeUid {
+    /// Creates a new JavaScript-safe unique identifier.
+    pub fn new() -> JsSafeUid {
+        let timestamp = timestamp_check(timestamp_gen().unwrap()).unwrap();
+        JsSafeUid::from_parts(timestamp, random_gen())
+    }
+
+    /// Initializes a monotonic generator of JavaScript-safe unique identifiers.
+    ///
+    /// The 9 bits are a counter starting at a random value below 256 every millisecond,
+    /// giving at least 256 UIDs per millisecond.
+    pub fn init_monotonic() -> JsSafeGenerator {
+        JsSafeGenerator::default()
+    }
+
+    /// Creates a JsSafeUid from the provided timestamp and random number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp does not fit in 44 bits or the random number does not fit in 9 bits.
+    pub fn from_parts(timestamp: u64, random: u64) -> JsSafeUid {
+        JsSafeUid::from_parts_checked(timestamp, random).unwrap()
+    }
+
+    /// Creates a JsSafeUid from the provided timestamp and random number,
+    /// returning an error if either part is out of range.
+    pub const fn from_parts_checked(timestamp: u64, random: u64) -> Result<JsSafeUid, Error> {
+        if let Err(err) = timestamp_check(timestamp) {
+            return Err(err);
+        }
+        if let Err(err) = rng_size_check(random) {
+            return Err(err);
+        }
+        Ok(JsSafeUid(timestamp << RANDOM_BITS | random))
+    }
+
+    /// Creates a JsSafeUid from its integer value, failing if it does not fit in 53 bits.
+    pub const fn from_u64(value: u64) -> Result<JsSafeUid, Error> {
+        match value_check(value) {
+            Ok(value) => Ok(JsSafeUid(value)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn get_timestamp(&self) -> u64 {
+        self.0 >> RANDOM_BITS
+    }
+
+    pub const fn get_random(&self) -> u64 {
+        self.0 & MAX_RANDOM
+    }
+
+    pub const fn to_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Converts to a double without losing precision, as JavaScript numbers do.
+    pub const fn to_f64(&self) -> f64 {
+        self.0 as f64
+    }
+}
+
+/// Generates a random number within the 9-bit range
+pub fn random_gen() -> u64 {
+    rand::rng().random_range(0..=MAX_RANDOM)
+}
+
+/// Monotonic generator of JavaScript-safe unique identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JsSafeGenerator {
+    pub(crate) last_ms: u64,
+    pub(crate) counter: u16,
+}
+
+impl JsSafeGenerator {
+    pub fn generate(&mut self) -> JsSafeUid {
+        loop {
+            match self.try_generate() {
+                Err(SmallUidError::MonotonicCounterLimit) => {
+                    // Delay until the next millisecond
+                    sleep(Duration::from_millis(1));
+                }
+                result => return result.unwrap(),
+            }
+        }
+    }
+
+    /// Generates a monotonic JsSafeUid, returning `MonotonicCounterLimit` instead of waiting
+    /// when the counter of the current millisecond is exhausted.
+    pub fn try_generate(&mut self) -> Result<JsSafeUid, Error> {
+        let timestamp = timestamp_check(timestamp_gen()?)?;
+        if timestamp > self.last_ms {
+            self.last_ms = timestamp;
+            self.counter = rand::rng().random_range(0..(1 << (RANDOM_BITS - 1)));
+        } else {
+            if self.counter as u64 >= MAX_RANDOM {
+                return Err(SmallUidError::MonotonicCounterLimit);
+            }
+            self.counter += 1;
+        }
+        JsSafeUid::from_parts_checked(self.last_ms, self.counter as u64)
+    }
+
+    pub fn generate_batch(&mut self, count: usize) -> Vec<JsSafeUid> {
+        let mut uids = Vec::with_capacity(count);
+        for _ in 0..count {
+            uids.push(self.generate());
+        }
+        uids
+    }
+
+    /// Returns an infinite iterator of monotonic JsSafeUids.
+    pub fn iter(&mut self) -> Iter<'_, Self> {
+        Iter::new(self)
+    }
+
+    /// Returns an infinite iterator of monotonic JsSafeUids that yields counter exhaustion
+    /// and clock errors instead of waiting or panicking.
+    pub fn try_iter(&mut self) -> TryIter<'_, Self> {
+        TryIter::new(self)
+    }
+}
+
+impl Generator for JsSafeGenerator {
+    type Item = JsSafeUid;
+
+    fn generate(&mut self) -> JsSafeUid {
+        JsSafeGenerator::generate(self)
+    }
+
+    fn try_generate(&mut self) -> Result<JsSafeUid, Error> {
+        JsSafeGenerator::try_generate(self)
+    }
+}
+
+/// Widens to a SmallUid with the same timestamp, the 9 random bits become the first random bits
+/// so the sort order is kept.
+impl From<JsSafeUid> for SmallUid {
+    fn from(value: JsSafeUid) -> Self {
+        SmallUid::from_parts(
+            value.get_timestamp(),
+            value.get_random() << (20 - RANDOM_BITS),
+        )
+    }
+}
+
+impl From<JsSafeUid> for u64 {
+    fn from(value: JsSafeUid) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u64> for JsSafeUid {
+    type Error = Error;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        JsSafeUid::from_u64(value)
+    }
+}
+
+impl TryFrom<String> for JsSafeUid {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        JsSafeUid::from_u64(SmallUid::try_from(value)?.to_u64())
+    }
+}
+
+impl From<JsSafeUid> for String {
+    fn from(value: JsSafeUid) -> Self {
+        value.to_string()
+    }
+}
+
+/// Same base64-url encoding as SmallUid.
+impl Display for JsSafeUid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        SmallUid(self.0).fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for JsSafeUid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for JsSafeUid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        JsSafeUid::from_u64(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checking::MAX_TIMESTAMP;
+
+    #[test]
+    fn test_new_is_js_safe() {
+        for _ in 0..100 {
+            let uid = JsSafeUid::new();
+            assert!(uid.to_u64() <= MAX_VALUE);
+            assert_eq!(uid.to_f64() as u64, uid.to_u64());
+            assert!(uid.get_timestamp() > 0);
+        }
+    }
+
+    #[test]
+    fn test_from_parts_boundaries() {
+        let max = JsSafeUid::from_parts(MAX_TIMESTAMP, MAX_RANDOM);
+        assert_eq!(max.to_u64(), MAX_VALUE);
+        assert_eq!(max.to_f64(), 9007199254740991.0);
+        assert!(matches!(
+            JsSafeUid::from_parts_checked(MAX_TIMESTAMP + 1, 0),
+            Err(SmallUidError::TimestampLimit)
+        ));
+        assert!(matches!(
+            JsSafeUid::from_parts_checked(0, MAX_RANDOM + 1),
+            Err(SmallUidError::RandomSizeLimit)
+        ));
+        assert!(matches!(
+            JsSafeUid::from_u64(MAX_VALUE + 1),
+            Err(SmallUidError::JsSafeLimit)
+        ));
+    }
+
+    #[test]
+    fn test_parts() {
+        let uid = JsSafeUid::from_parts(1_700_000_000_000, 300);
+        assert_eq!(uid.get_timestamp(), 1_700_000_000_000);
+        assert_eq!(uid.get_random(), 300);
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        let uid = JsSafeUid::new();
+        assert_eq!(JsSafeUid::try_from(uid.to_string()).unwrap(), uid);
+        let too_large = SmallUid(MAX_VALUE + 1).to_string();
+        assert!(matches!(
+            JsSafeUid::try_from(too_large),
+            Err(SmallUidError::JsSafeLimit)
+        ));
+    }
+
+    #[test]
+    fn test_into_smalluid_keeps_order() {
+        let a = JsSafeUid::from_parts(10, MAX_RANDOM);
+        let b = JsSafeUid::from_parts(11, 0);
+        let c = JsSafeUid::from_parts(11, 1);
+        let (sa, sb, sc) = (SmallUid::from(a), SmallUid::from(b), SmallUid::from(c));
+        assert!(sa < sb && sb < sc);
+        assert_eq!(sa.get_timestamp(), 10);
+    }
+
+    #[test]
+    fn test_monotonic() {
+        let mut generator = JsSafeUid::init_monotonic();
+        let uids = generator.generate_batch(2048);
+        for i in 1..uids.len() {
+            assert!(
+                uids[i] > uids[i - 1],
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+        let uids: Vec<JsSafeUid> = generator.iter().take(10).collect();
+        assert_eq!(uids.len(), 10);
+    }
+
+    #[test]
+    fn test_monotonic_exhaustion() {
+        let mut generator = JsSafeUid::init_monotonic();
+        generator.last_ms = MAX_TIMESTAMP;
+        generator.counter = MAX_RANDOM as u16;
+        assert!(matches!(
+            generator.try_generate(),
+            Err(SmallUidError::MonotonicCounterLimit)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_number() {
+        let uid = JsSafeUid::from_parts(MAX_TIMESTAMP, MAX_RANDOM);
+        let json = serde_json::to_string(&uid).unwrap();
+        assert_eq!(json, "9007199254740991");
+        let deserialized: JsSafeUid = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, uid);
+        assert!(serde_json::from_str::<JsSafeUid>("9007199254740992").is_err());
+    }
+}
@@ -1,9 +1,18 @@
 //!
 #![doc = include_str!("../README.md")]
 //!
+mod batch;
 /// Checking timestamp and random number
 pub mod checking;
+/// Converting timestamps to and from date and time types
+pub mod datetime;
+/// Diesel encoding for SQLite, Postgres and MySQL
+#[cfg(feature = "diesel")]
+pub mod diesel;
 mod error;
+/// C ABI for use from C, C++ and other languages with a C FFI
+#[cfg(feature = "ffi")]
+pub mod ffi;
 /// Generating timestamp and random number
 mod generation;
 
@@ -13,17 +22,77 @@ mod monotonic;
 
 pub use monotonic::MonotonicGenerator;
 
+mod distinct;
+
+pub use distinct::DistinctGenerator;
+
+mod inspect;
+
+pub use inspect::SmallUidInfo;
+
+#[cfg(any(feature = "uuid", feature = "ulid"))]
+mod interop;
+mod navigation;
+mod range;
+mod resolution;
+
+pub use resolution::Resolution;
+
+pub use range::SmallUidRange;
+
+/// gRPC service issuing SmallUids
+#[cfg(feature = "grpc")]
+pub mod grpc;
+/// Iterators over generators
+pub mod iter;
+/// JavaScript-safe 53-bit layout
+pub mod js_safe;
+/// Node-API addon built with napi-rs
+#[cfg(feature = "node")]
+mod node;
+/// Parallel batch generation
+#[cfg(feature = "rayon")]
+mod parallel;
+/// Protobuf messages
+#[cfg(feature = "proto")]
+pub mod proto;
+/// Python module built with PyO3
+#[cfg(feature = "python")]
+mod python;
+/// rusqlite encoding and SQL functions
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+/// Serde representations
+#[cfg(feature = "serde")]
+pub mod serde;
+/// HTTP service issuing SmallUids
+#[cfg(feature = "server")]
+pub mod server;
+/// Generator shared by the services
+#[cfg(any(feature = "server", feature = "grpc"))]
+mod shared;
+/// sqlx encoding for Postgres, MySQL and SQLite
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+/// Async streams over generators
+#[cfg(feature = "stream")]
+pub mod stream;
+/// JavaScript classes exported with wasm-bindgen
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "wasm")]
+pub use wasm::{WasmMonotonicGenerator, WasmSmallUid};
+
 #[cfg(test)]
 mod test;
 
+use checking::{rng_size_check, timestamp_check};
 pub use error::SmallUidError;
 use generation::assemble;
 use std::fmt::Display;
 type Error = SmallUidError;
 
-#[cfg(all(not(target_arch = "wasm32"), feature = "serde"))]
-use serde::{Deserialize, Serialize};
-
 #[doc = r#"# Examples
 
 ## Generate a single SmallUid
@@ -42,6 +111,12 @@ let ids = SmallUid::batch_new(5);
 for id in ids {
     println!("Batch SmallUid: {}", id);
 }
+
+let mut ids = [SmallUid::default(); 5];
+SmallUid::fill(&mut ids);
+
+let mut ids = Vec::new();
+SmallUid::extend_into(&mut ids, 5);
 ```
 
 ## Generate SmallUids using monotonic generator
@@ -60,11 +135,32 @@ for id in ids {
 
 let timestamp = timestamp_gen().unwrap();
 let full_id: [SmallUid; 1024] = generator.generate_full(timestamp);
+```
+
+## Generate random SmallUids that never collide within a millisecond
+```rust
+use small_uid::SmallUid;
+
+let mut generator = SmallUid::init_distinct();
+let id = generator.generate();
+let ids = generator.generate_batch(1024);
+assert!(!ids.contains(&id));
+```
+
+## Iterate over a generator
+```rust
+use small_uid::SmallUid;
+
+let mut generator = SmallUid::init_monotonic();
+let ids: Vec<SmallUid> = generator.iter().take(3).collect();
+
+for result in generator.try_iter().take(3) {
+    match result {
+        Ok(id) => println!("SmallUid: {}", id),
+        Err(err) => println!("Try again later: {}", err),
+    }
+}
 ```"#]
-#[cfg_attr(
-    all(not(target_arch = "wasm32"), feature = "serde"),
-    derive(Serialize, Deserialize)
-)]
 #[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct SmallUid(pub u64);
 
@@ -75,41 +171,100 @@ impl SmallUid {
     }
 
     /// Creates a batch of small unique identifiers.
+    ///
+    /// The ids of a batch never collide with each other.
     pub fn batch_new(count: usize) -> Vec<SmallUid> {
-        let mut smalluids = Vec::new();
-        for _ in 0..count {
-            smalluids.push(generation::generate().unwrap());
-        }
+        let mut smalluids = Vec::with_capacity(count);
+        SmallUid::extend_into(&mut smalluids, count);
         smalluids
     }
 
+    /// Fills the slice with small unique identifiers.
+    ///
+    /// The clock is read once per chunk of ids and randomness is drawn in bulk,
+    /// the ids of the slice never collide with each other.
+    pub fn fill(smalluids: &mut [SmallUid]) {
+        batch::fill(smalluids).unwrap()
+    }
+
+    /// Appends `count` small unique identifiers to the vector.
+    ///
+    /// The appended ids never collide with each other.
+    pub fn extend_into(smalluids: &mut Vec<SmallUid>, count: usize) {
+        batch::extend_into(smalluids, count).unwrap()
+    }
+
     /// Initializes a monotonic generator.
     ///
     /// Monotonic SmallUid replaces the first 10 bits of randomness with an increment,
     /// giving 1024 UIDs per millisecond.
     pub fn init_monotonic() -> MonotonicGenerator {
         MonotonicGenerator {
+            resolution: Resolution::MILLISECOND,
             last_ms: 0,
             lower_bits: 0,
             upper_counter: 0,
         }
     }
 
+    /// Initializes a monotonic generator with a timestamp of the given resolution.
+    ///
+    /// The counter gives 1024 UIDs per tick of the resolution.
+    pub fn init_monotonic_with_resolution(resolution: Resolution) -> MonotonicGenerator {
+        MonotonicGenerator {
+            resolution,
+            ..SmallUid::init_monotonic()
+        }
+    }
+
+    /// Initializes a distinct generator.
+    ///
+    /// Distinct SmallUid keeps the 20 bits of randomness but never repeats a random number
+    /// within the same millisecond, giving up to 1,048,576 unique UIDs per millisecond.
+    pub fn init_distinct() -> DistinctGenerator {
+        DistinctGenerator::default()
+    }
+
     /// Creates a SmallUid from the provided timestamp and random number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp does not fit in 44 bits or the random number does not fit in 20 bits.
+    /// Use [`SmallUid::from_parts_checked`] for a fallible version.
     pub fn from_parts(timestamp: u64, random: u64) -> SmallUid {
-        assemble(timestamp, random)
+        Self::from_parts_checked(timestamp, random).unwrap()
+    }
+
+    /// Creates a SmallUid from the provided timestamp and random number,
+    /// returning an error if either part is out of range.
+    pub const fn from_parts_checked(timestamp: u64, random: u64) -> Result<SmallUid, Error> {
+        if let Err(err) = timestamp_check(timestamp) {
+            return Err(err);
+        }
+        if let Err(err) = rng_size_check(random) {
+            return Err(err);
+        }
+        Ok(assemble(timestamp, random))
     }
 
     /// Creates a SmallUid from the provided timestamp.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp does not fit in 44 bits.
     pub fn from_timestamp(timestamp: u64) -> SmallUid {
         let random = generation::random_gen();
-        assemble(timestamp, random)
+        Self::from_parts(timestamp, random)
     }
 
     /// Creates a SmallUid from the provided random number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number does not fit in 20 bits.
     pub fn from_random(random: u64) -> SmallUid {
         let timestamp = generation::timestamp_gen().unwrap();
-        assemble(timestamp, random)
+        Self::from_parts(timestamp, random)
     }
 
     /// Take and normalze timestamp from SmallUid.
@@ -124,6 +279,54 @@ impl SmallUid {
     pub fn to_u64(&self) -> u64 {
         self.0
     }
+
+    /// Converts the SmallUid to an i64 with the same value, failing if it is larger than `i64::MAX`.
+    ///
+    /// Only SmallUids with a timestamp after year 2248 have the top bit set.
+    pub const fn to_i64(&self) -> Result<i64, Error> {
+        if self.0 > i64::MAX as u64 {
+            return Err(SmallUidError::I64Range);
+        }
+        Ok(self.0 as i64)
+    }
+
+    /// Creates a SmallUid from an i64 with the same value, failing if it is negative.
+    pub const fn from_i64(value: i64) -> Result<SmallUid, Error> {
+        if value < 0 {
+            return Err(SmallUidError::I64Range);
+        }
+        Ok(SmallUid(value as u64))
+    }
+
+    /// Converts the SmallUid to an i64 that sorts like the SmallUid.
+    ///
+    /// The sign bit is flipped, so the smallest SmallUid maps to `i64::MIN` and the largest
+    /// to `i64::MAX`. Use it to store SmallUids in signed `BIGINT` columns, the database
+    /// integrations of this crate store them this way on every backend without unsigned integers.
+    pub const fn to_i64_sortable(&self) -> i64 {
+        (self.0 ^ (1 << 63)) as i64
+    }
+
+    /// Creates a SmallUid from an i64 made by [`SmallUid::to_i64_sortable`].
+    pub const fn from_i64_sortable(value: i64) -> SmallUid {
+        SmallUid(value as u64 ^ (1 << 63))
+    }
+}
+
+impl TryFrom<SmallUid> for i64 {
+    type Error = Error;
+
+    fn try_from(value: SmallUid) -> Result<Self, Self::Error> {
+        value.to_i64()
+    }
+}
+
+impl TryFrom<i64> for SmallUid {
+    type Error = Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        SmallUid::from_i64(value)
+    }
 }
 
 impl From<u64> for SmallUid {
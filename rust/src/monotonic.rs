@@ -4,16 +4,23 @@ use rand::Rng;
 
 use crate::{
     Error, SmallUid,
-    generation::{assemble, timestamp_gen},
+    checking::timestamp_check,
+    generation::assemble,
+    iter::{Generator, Iter, TryIter},
+    resolution::Resolution,
 };
 
+#[cfg(feature = "stream")]
+use crate::stream::{GeneratorStream, TryGeneratorStream};
+
 pub fn random_gen() -> u16 {
     rand::rng().random_range(0..(1 << 10)) // Generate a 10-bit random number
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct MonotonicGenerator {
-    pub(crate) last_ms: u64,
+    pub(crate) resolution: Resolution,
+    pub(crate) last_ms: u64,       // in ticks of the resolution
     pub(crate) lower_bits: u16,    // 10-bit random part
     pub(crate) upper_counter: u16, // 10-bit counter part
 }
@@ -23,6 +30,36 @@ impl MonotonicGenerator {
         generate(self).unwrap()
     }
 
+    /// Generates a monotonic SmallUid, returning `MonotonicCounterLimit` instead of waiting
+    /// when the counter of the current millisecond is exhausted.
+    pub fn try_generate(&mut self) -> Result<SmallUid, Error> {
+        try_generate(self)
+    }
+
+    /// Returns an infinite iterator of monotonic SmallUids.
+    pub fn iter(&mut self) -> Iter<'_, Self> {
+        Iter::new(self)
+    }
+
+    /// Returns an infinite iterator of monotonic SmallUids that yields counter exhaustion
+    /// and clock errors instead of waiting or panicking.
+    pub fn try_iter(&mut self) -> TryIter<'_, Self> {
+        TryIter::new(self)
+    }
+
+    /// Returns an infinite stream of monotonic SmallUids.
+    #[cfg(feature = "stream")]
+    pub fn stream(&mut self) -> GeneratorStream<'_, Self> {
+        GeneratorStream::new(self)
+    }
+
+    /// Returns an infinite stream of monotonic SmallUids that yields exhaustion
+    /// and clock errors instead of waiting or panicking.
+    #[cfg(feature = "stream")]
+    pub fn try_stream(&mut self) -> TryGeneratorStream<'_, Self> {
+        TryGeneratorStream::new(self)
+    }
+
     pub fn generate_batch(&mut self, count: usize) -> Vec<SmallUid> {
         let mut smalluids = Vec::new();
         for _ in 0..count {
@@ -34,7 +71,12 @@ impl MonotonicGenerator {
     /// Generate all possible monotonic SmallUids for a given timestamp (10-bit increment: 1024 UIDs)
     ///
     /// Most modern machine should be able to run in the µs range even in debug mode, though your mileage may varies
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp does not fit in 44 bits.
     pub fn generate_full(&mut self, timestamp: u64) -> [SmallUid; 1024] {
+        let timestamp = timestamp_check(timestamp).unwrap();
         std::array::from_fn(|i| {
             let random = random_gen() & 0x3FF;
             // For each i in 0..1024, use i as the 10-bit increment
@@ -44,6 +86,58 @@ impl MonotonicGenerator {
     }
 }
 
+impl Generator for MonotonicGenerator {
+    type Item = SmallUid;
+
+    fn generate(&mut self) -> SmallUid {
+        MonotonicGenerator::generate(self)
+    }
+
+    fn try_generate(&mut self) -> Result<SmallUid, Error> {
+        MonotonicGenerator::try_generate(self)
+    }
+}
+
+/// Generates a monotonic random value within 20-bit space
+pub fn monotonic_random_gen(
+    generator: &mut MonotonicGenerator,
+    timestamp: u64,
+) -> Result<u32, Error> {
+    if timestamp > generator.last_ms {
+        generator.last_ms = timestamp;
+        generator.lower_bits = random_gen() & 0x3FF; // Get new 10-bit randomness
+        generator.upper_counter = 0; // Reset the counter
+    } else {
+        if generator.upper_counter >= 0x3FF {
+            return Err(Error::MonotonicCounterLimit);
+        }
+        generator.upper_counter += 1;
+    }
+
+    // Combine upper 10-bit counter with lower 10-bit random
+    Ok(((generator.upper_counter as u32) << 10) | generator.lower_bits as u32)
+}
+
+/// Generates a monotonic SmallUid without waiting for the next millisecond
+pub fn try_generate(generator: &mut MonotonicGenerator) -> Result<SmallUid, Error> {
+    let timestamp = generator.resolution.timestamp_gen()?;
+    let random = monotonic_random_gen(generator, timestamp)?;
+    Ok(assemble(generator.last_ms, random as u64))
+}
+
+/// Generates a monotonic SmallUid
+pub fn generate(generator: &mut MonotonicGenerator) -> Result<SmallUid, Error> {
+    loop {
+        match try_generate(generator) {
+            Err(Error::MonotonicCounterLimit) => {
+                // Delay until the next millisecond
+                sleep(Duration::from_millis(1));
+            }
+            result => return result,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +182,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_clock_backwards_keeps_last_timestamp() {
+        let mut generator = MonotonicGenerator::default();
+        // A millisecond the clock has not reached yet, as if it went backwards since
+        let last_ms = timestamp_gen().unwrap() + 60_000;
+        generator.last_ms = last_ms;
+        let previous = assemble(last_ms, 0);
+        let uid = generator.generate();
+        assert_eq!(uid.get_timestamp(), last_ms);
+        assert!(uid > previous, "UID sorts before the previous one");
+    }
+
     #[test]
     fn test_generate_full_monotonicity() {
         let mut generator = MonotonicGenerator::default();
@@ -113,37 +219,3 @@ mod tests {
         }
     }
 }
-
-/// Generates a monotonic random value within 20-bit space
-pub fn monotonic_random_gen(
-    generator: &mut MonotonicGenerator,
-    timestamp: u64,
-) -> Result<u32, Error> {
-    if timestamp > generator.last_ms {
-        generator.last_ms = timestamp;
-        generator.lower_bits = random_gen() & 0x3FF; // Get new 10-bit randomness
-        generator.upper_counter = 0; // Reset the counter
-    } else {
-        if generator.upper_counter >= 0x3FF {
-            return Err(Error::MonotonicCounterLimit);
-        }
-        generator.upper_counter += 1;
-    }
-
-    // Combine upper 10-bit counter with lower 10-bit random
-    Ok(((generator.upper_counter as u32) << 10) | generator.lower_bits as u32)
-}
-
-/// Generates a monotonic SmallUid
-pub fn generate(generator: &mut MonotonicGenerator) -> Result<SmallUid, Error> {
-    loop {
-        let timestamp = timestamp_gen()?;
-        match monotonic_random_gen(generator, timestamp) {
-            Ok(random) => return Ok(assemble(timestamp, random as u64)),
-            Err(_) => {
-                // Delay until the next millisecond
-                sleep(Duration::from_millis(1));
-            }
-        }
-    }
-}
@@ -0,0 +1,133 @@
+use crate::SmallUid;
+
+#[cfg(target_arch = "wasm32")]
+use web_time::{Duration, SystemTime};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, SystemTime};
+
+impl SmallUid {
+    /// Returns the next SmallUid in sort order, or None for the largest one.
+    ///
+    /// The random part of the largest SmallUid of a timestamp rolls over into the next timestamp.
+    pub const fn successor(&self) -> Option<SmallUid> {
+        self.checked_add(1)
+    }
+
+    /// Returns the previous SmallUid in sort order, or None for the smallest one.
+    ///
+    /// The random part of the smallest SmallUid of a timestamp rolls back into the previous timestamp.
+    pub const fn predecessor(&self) -> Option<SmallUid> {
+        self.checked_sub(1)
+    }
+
+    /// Returns the SmallUid `count` positions later in sort order, or None on overflow.
+    pub const fn checked_add(&self, count: u64) -> Option<SmallUid> {
+        match self.0.checked_add(count) {
+            Some(value) => Some(SmallUid(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the SmallUid `count` positions earlier in sort order, or None on underflow.
+    pub const fn checked_sub(&self, count: u64) -> Option<SmallUid> {
+        match self.0.checked_sub(count) {
+            Some(value) => Some(SmallUid(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the time between the timestamp of an earlier SmallUid and this one,
+    /// or None if `earlier` is in fact later.
+    pub fn checked_elapsed_since(&self, earlier: &SmallUid) -> Option<Duration> {
+        self.get_timestamp()
+            .checked_sub(earlier.get_timestamp())
+            .map(Duration::from_millis)
+    }
+
+    /// Returns the time between the timestamp of an earlier SmallUid and this one,
+    /// or zero if `earlier` is in fact later.
+    pub fn elapsed_since(&self, earlier: &SmallUid) -> Duration {
+        self.checked_elapsed_since(earlier)
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns the time elapsed since the SmallUid timestamp, or zero if it is in the future.
+    pub fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.datetime())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns true if the SmallUid timestamp is older than the duration.
+    pub fn is_older_than(&self, duration: Duration) -> bool {
+        self.age() > duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checking::{MAX_RANDOM, MAX_TIMESTAMP};
+
+    #[test]
+    fn test_successor_predecessor() {
+        let uid = SmallUid::from_parts(10, 5);
+        assert_eq!(uid.successor(), Some(SmallUid::from_parts(10, 6)));
+        assert_eq!(uid.predecessor(), Some(SmallUid::from_parts(10, 4)));
+
+        let last_of_ms = SmallUid::from_parts(10, MAX_RANDOM);
+        assert_eq!(last_of_ms.successor(), Some(SmallUid::from_parts(11, 0)));
+        let first_of_ms = SmallUid::from_parts(11, 0);
+        assert_eq!(
+            first_of_ms.predecessor(),
+            Some(SmallUid::from_parts(10, MAX_RANDOM))
+        );
+
+        assert_eq!(
+            SmallUid::from_parts(MAX_TIMESTAMP, MAX_RANDOM).successor(),
+            None
+        );
+        assert_eq!(SmallUid::from_parts(0, 0).predecessor(), None);
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let uid = SmallUid::from_parts(1, 0);
+        assert_eq!(uid.checked_add(1 << 20), Some(SmallUid::from_parts(2, 0)));
+        assert_eq!(uid.checked_sub(1 << 20), Some(SmallUid::from_parts(0, 0)));
+        assert_eq!(uid.checked_sub((1 << 20) + 1), None);
+        assert_eq!(SmallUid(u64::MAX - 1).checked_add(2), None);
+    }
+
+    #[test]
+    fn test_elapsed_since() {
+        let earlier = SmallUid::from_parts(1_000, MAX_RANDOM);
+        let later = SmallUid::from_parts(1_250, 0);
+        assert_eq!(later.elapsed_since(&earlier), Duration::from_millis(250));
+        assert_eq!(
+            later.checked_elapsed_since(&earlier),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(earlier.elapsed_since(&later), Duration::ZERO);
+        assert_eq!(earlier.checked_elapsed_since(&later), None);
+        assert_eq!(
+            SmallUid::from_parts(MAX_TIMESTAMP, 0).elapsed_since(&SmallUid::from_parts(0, 0)),
+            Duration::from_millis(MAX_TIMESTAMP)
+        );
+    }
+
+    #[test]
+    fn test_age() {
+        let old = SmallUid::from_parts(0, 0);
+        assert!(old.age() > Duration::from_secs(365 * 24 * 3600));
+        assert!(old.is_older_than(Duration::from_secs(3600)));
+
+        let future = SmallUid::from_parts(MAX_TIMESTAMP, 0);
+        assert_eq!(future.age(), Duration::ZERO);
+        assert!(!future.is_older_than(Duration::ZERO));
+
+        let now = SmallUid::new();
+        assert!(!now.is_older_than(Duration::from_secs(3600)));
+    }
+}
@@ -0,0 +1,151 @@
+// Node-API addon built with napi-rs, an alternative to the wasm build for server-side Node.
+//
+// The classes mirror the `wasm` feature: ids are returned as `bigint` and strings match the
+// `Display` implementation of SmallUid.
+
+use napi::bindgen_prelude::BigInt;
+use napi_derive::napi;
+
+use crate::{Error, MonotonicGenerator, SmallUid};
+
+impl From<Error> for napi::Error {
+    fn from(err: Error) -> Self {
+        napi::Error::from_reason(err.to_string())
+    }
+}
+
+/// A 64-bit sortable unique identifier, exported to Node as `SmallUid`.
+#[napi(js_name = "SmallUid")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeSmallUid {
+    inner: SmallUid,
+}
+
+#[napi]
+impl NodeSmallUid {
+    /// Creates a new SmallUid.
+    #[napi(constructor)]
+    pub fn new() -> napi::Result<NodeSmallUid> {
+        Ok(crate::generation::generate()?.into())
+    }
+
+    /// Parses a base64-url encoded SmallUid.
+    #[napi(factory)]
+    pub fn from_string(value: String) -> napi::Result<NodeSmallUid> {
+        Ok(SmallUid::try_from(value)?.into())
+    }
+
+    /// Creates a SmallUid from its 64-bit value.
+    #[napi(factory)]
+    pub fn from_big_int(value: BigInt) -> napi::Result<NodeSmallUid> {
+        match value.get_u64() {
+            (false, value, true) => Ok(SmallUid(value).into()),
+            _ => Err(napi::Error::from_reason(
+                "Value does not fit in an unsigned 64-bit integer",
+            )),
+        }
+    }
+
+    /// Returns the base64-url encoding.
+    #[napi(js_name = "toString")]
+    pub fn to_js_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// Returns the timestamp in milliseconds since the unix epoch, exact as a JS number.
+    #[napi]
+    pub fn timestamp(&self) -> f64 {
+        self.inner.get_timestamp() as f64
+    }
+
+    /// Returns the 20 random bits.
+    #[napi]
+    pub fn random(&self) -> u32 {
+        self.inner.get_random() as u32
+    }
+
+    /// Returns the 64-bit value as a BigInt.
+    #[napi]
+    pub fn to_big_int(&self) -> BigInt {
+        BigInt::from(self.inner.to_u64())
+    }
+}
+
+impl From<SmallUid> for NodeSmallUid {
+    fn from(value: SmallUid) -> Self {
+        NodeSmallUid { inner: value }
+    }
+}
+
+impl From<NodeSmallUid> for SmallUid {
+    fn from(value: NodeSmallUid) -> Self {
+        value.inner
+    }
+}
+
+/// Monotonic generator of SmallUids, exported to Node as `MonotonicGenerator`.
+#[napi(js_name = "MonotonicGenerator")]
+#[derive(Debug, Default)]
+pub struct NodeMonotonicGenerator {
+    inner: MonotonicGenerator,
+}
+
+#[napi]
+impl NodeMonotonicGenerator {
+    #[napi(constructor)]
+    pub fn new() -> NodeMonotonicGenerator {
+        NodeMonotonicGenerator {
+            inner: SmallUid::init_monotonic(),
+        }
+    }
+
+    /// Generates the next SmallUid, always greater than the previous one.
+    #[napi]
+    pub fn generate(&mut self) -> NodeSmallUid {
+        self.inner.generate().into()
+    }
+
+    /// Generates `count` SmallUids in increasing order.
+    #[napi]
+    pub fn generate_batch(&mut self, count: u32) -> Vec<NodeSmallUid> {
+        self.inner
+            .iter()
+            .take(count as usize)
+            .map(NodeSmallUid::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_uid_accessors() {
+        let uid = SmallUid::from_parts(1_700_000_000_000, 0xABCDE);
+        let node = NodeSmallUid::from(uid);
+        assert_eq!(node.timestamp(), 1_700_000_000_000.0);
+        assert_eq!(node.random(), 0xABCDE);
+        assert_eq!(node.to_big_int().get_u64(), (false, uid.to_u64(), true));
+        assert_eq!(node.to_js_string(), uid.to_string());
+        assert_eq!(NodeSmallUid::from_string(uid.to_string()).unwrap(), node);
+        assert_eq!(
+            NodeSmallUid::from_big_int(BigInt::from(uid.to_u64())).unwrap(),
+            node
+        );
+    }
+
+    #[test]
+    fn test_monotonic_generator() {
+        let mut generator = NodeMonotonicGenerator::new();
+        let uids = generator.generate_batch(2048);
+        for i in 1..uids.len() {
+            assert!(
+                uids[i].inner > uids[i - 1].inner,
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+        assert!(generator.generate().inner > uids[2047].inner);
+    }
+}
@@ -0,0 +1,147 @@
+use std::{thread::sleep, time::Duration};
+
+use rayon::prelude::*;
+
+use crate::{
+    Error, MonotonicGenerator, SmallUid, batch, generation::assemble, monotonic::random_gen,
+};
+
+/// Upper bound on the partitions of the random space, each one keeps 16,384 random numbers per millisecond.
+const MAX_PARTITIONS: usize = 64;
+
+/// Number of counter values in one millisecond of monotonic ids.
+const COUNTER_SPACE: u64 = 1 << 10;
+
+/// Fills the slice in parallel, giving each worker its own partition of the random space.
+pub fn par_fill(uids: &mut [SmallUid]) -> Result<(), Error> {
+    if uids.is_empty() {
+        return Ok(());
+    }
+    let partitions = rayon::current_num_threads()
+        .next_power_of_two()
+        .min(MAX_PARTITIONS);
+    let chunk_size = uids.len().div_ceil(partitions);
+    uids.par_chunks_mut(chunk_size)
+        .enumerate()
+        .try_for_each(|(partition, chunk)| {
+            batch::fill_partition(chunk, partition as u32, partitions as u32)
+        })
+}
+
+/// Fills the slice in parallel with monotonic SmallUids following the generator state.
+///
+/// The ids take the counter values left in the current millisecond, filled in parallel, then
+/// those of the next milliseconds as the clock reaches them, so no id is dated in the future.
+pub fn par_fill_monotonic(
+    generator: &mut MonotonicGenerator,
+    uids: &mut [SmallUid],
+) -> Result<(), Error> {
+    let mut rest = uids;
+    while !rest.is_empty() {
+        let timestamp = generator.resolution.timestamp_gen()?;
+        let first_counter = if timestamp > generator.last_ms {
+            generator.last_ms = timestamp;
+            generator.lower_bits = random_gen();
+            0
+        } else if (generator.upper_counter as u64) < COUNTER_SPACE - 1 {
+            generator.upper_counter as u64 + 1
+        } else {
+            // Delay until the next millisecond
+            sleep(Duration::from_millis(1));
+            continue;
+        };
+        let count = rest.len().min((COUNTER_SPACE - first_counter) as usize);
+        let (chunk, next) = rest.split_at_mut(count);
+        let timestamp = generator.last_ms;
+        chunk.par_iter_mut().enumerate().for_each(|(i, uid)| {
+            let counter = first_counter + i as u64;
+            *uid = assemble(timestamp, counter << 10 | random_gen() as u64);
+        });
+        generator.upper_counter = (first_counter + count as u64 - 1) as u16;
+        rest = next;
+    }
+    Ok(())
+}
+
+impl SmallUid {
+    /// Creates a batch of small unique identifiers using all rayon worker threads.
+    ///
+    /// Every worker draws from its own partition of the random space, so the ids of
+    /// the batch never collide with each other.
+    pub fn par_batch_new(count: usize) -> Vec<SmallUid> {
+        let mut smalluids = vec![SmallUid::default(); count];
+        par_fill(&mut smalluids).unwrap();
+        smalluids
+    }
+}
+
+impl MonotonicGenerator {
+    /// Generates a batch of monotonic SmallUids using all rayon worker threads.
+    ///
+    /// The batch is sorted and takes at most 1024 ids per millisecond, a batch larger than the
+    /// counter values left in the current millisecond waits for the clock like `generate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock fails or is beyond the 44-bit timestamp range.
+    pub fn par_generate_batch(&mut self, count: usize) -> Vec<SmallUid> {
+        let mut smalluids = vec![SmallUid::default(); count];
+        par_fill_monotonic(self, &mut smalluids).unwrap();
+        smalluids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_par_batch_new_uniqueness() {
+        let uids = SmallUid::par_batch_new(1 << 18);
+        assert_eq!(uids.len(), 1 << 18);
+        let mut seen = HashSet::new();
+        for uid in uids.iter() {
+            assert!(seen.insert(uid), "Duplicate UID found in parallel batch");
+        }
+    }
+
+    #[test]
+    fn test_par_batch_new_empty() {
+        assert!(SmallUid::par_batch_new(0).is_empty());
+    }
+
+    #[test]
+    fn test_par_generate_batch_sorted() {
+        let mut generator = SmallUid::init_monotonic();
+        let uids = generator.par_generate_batch(10_000);
+        assert_eq!(uids.len(), 10_000);
+        for i in 1..uids.len() {
+            assert!(
+                uids[i] > uids[i - 1],
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_par_generate_batch_waits_for_the_clock() {
+        let mut generator = SmallUid::init_monotonic();
+        let uids = generator.par_generate_batch(5000);
+        let now = crate::timestamp_gen().unwrap();
+        assert!(uids[uids.len() - 1].get_timestamp() <= now);
+        assert!(generator.last_ms <= now);
+        assert!(generator.generate().get_timestamp() <= crate::timestamp_gen().unwrap());
+    }
+
+    #[test]
+    fn test_par_generate_batch_continues_generator() {
+        let mut generator = SmallUid::init_monotonic();
+        let before = generator.generate();
+        let batch = generator.par_generate_batch(3000);
+        let after = generator.generate();
+        assert!(batch[0] > before);
+        assert!(after > batch[batch.len() - 1]);
+    }
+}
@@ -0,0 +1,80 @@
+//! Protobuf messages generated with prost from `proto/small_uid.proto`.
+//!
+//! The `SmallUid` message carries the id as a `fixed64`, it converts to and from
+//! [`crate::SmallUid`] without loss. With the `grpc` feature this module also holds the
+//! generated `SmallUidService` client and server.
+
+include!(concat!(env!("OUT_DIR"), "/small_uid.v1.rs"));
+
+use crate::SmallUidInfo;
+
+impl From<crate::SmallUid> for SmallUid {
+    fn from(value: crate::SmallUid) -> Self {
+        SmallUid {
+            value: value.to_u64(),
+        }
+    }
+}
+
+impl From<SmallUid> for crate::SmallUid {
+    fn from(value: SmallUid) -> Self {
+        crate::SmallUid(value.value)
+    }
+}
+
+impl From<SmallUidInfo> for DecodeResponse {
+    fn from(info: SmallUidInfo) -> Self {
+        DecodeResponse {
+            id: Some(info.id.into()),
+            text: info.id.to_string(),
+            hex: info.hex,
+            layout: info.layout,
+            timestamp: info.timestamp,
+            utc: info.utc,
+            age_ms: info.age_ms,
+            random: info.random as u32,
+            monotonic_counter: info.monotonic_counter as u32,
+            monotonic_random: info.monotonic_random as u32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_round_trip() {
+        for uid in [
+            crate::SmallUid(1),
+            crate::SmallUid(u64::MAX),
+            crate::SmallUid::new(),
+        ] {
+            let message = SmallUid::from(uid);
+            let bytes = message.encode_to_vec();
+            // Tag byte followed by the 8 little-endian bytes of the fixed64
+            assert_eq!(bytes.len(), 9);
+            let decoded = SmallUid::decode(bytes.as_slice()).unwrap();
+            assert_eq!(crate::SmallUid::from(decoded), uid);
+        }
+        // proto3 leaves default values out of the wire format
+        let zero = SmallUid::from(crate::SmallUid(0)).encode_to_vec();
+        assert!(zero.is_empty());
+        assert_eq!(
+            crate::SmallUid::from(SmallUid::decode(zero.as_slice()).unwrap()),
+            crate::SmallUid(0)
+        );
+    }
+
+    #[test]
+    fn test_decode_response() {
+        let uid = crate::SmallUid::from_parts(1_700_000_000_123, 0x5903C);
+        let response = DecodeResponse::from(uid.inspect());
+        assert_eq!(response.id, Some(uid.into()));
+        assert_eq!(response.text, uid.to_string());
+        assert_eq!(response.utc, "2023-11-14T22:13:20.123Z");
+        assert_eq!(response.monotonic_counter, 356);
+        assert_eq!(response.monotonic_random, 60);
+    }
+}
@@ -0,0 +1,155 @@
+// Python module built with PyO3, packaged as an abi3 wheel by maturin (see pyproject.toml).
+//
+// The Rust types are wrapped rather than exported directly so their Python names and methods
+// follow Python conventions without leaking into the Rust API.
+
+use pyo3::{
+    create_exception,
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyType},
+};
+
+use crate::{MonotonicGenerator, SmallUid};
+
+create_exception!(
+    small_uid,
+    SmallUidError,
+    PyValueError,
+    "Raised when a SmallUid cannot be parsed or generated."
+);
+
+impl From<crate::SmallUidError> for PyErr {
+    fn from(err: crate::SmallUidError) -> Self {
+        SmallUidError::new_err(err.to_string())
+    }
+}
+
+/// A 64-bit sortable unique identifier, exposed to Python as `small_uid.SmallUid`.
+#[pyclass(name = "SmallUid", module = "small_uid", frozen, eq, ord, hash)]
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PySmallUid(SmallUid);
+
+#[pymethods]
+impl PySmallUid {
+    /// Generates a new SmallUid.
+    #[new]
+    fn new() -> PyResult<Self> {
+        Ok(PySmallUid(crate::generation::generate()?))
+    }
+
+    /// Parses a base64-url encoded SmallUid.
+    #[classmethod]
+    fn parse(_cls: &Bound<'_, PyType>, value: &str) -> PyResult<Self> {
+        Ok(PySmallUid(SmallUid::try_from(value.to_string())?))
+    }
+
+    /// Creates a SmallUid from its 64-bit value.
+    #[classmethod]
+    fn from_int(_cls: &Bound<'_, PyType>, value: u64) -> Self {
+        PySmallUid(SmallUid(value))
+    }
+
+    /// Timestamp in milliseconds since the unix epoch.
+    #[getter]
+    fn timestamp(&self) -> u64 {
+        self.0.get_timestamp()
+    }
+
+    /// The 20 random bits.
+    #[getter]
+    fn random(&self) -> u64 {
+        self.0.get_random()
+    }
+
+    /// Timestamp as an aware `datetime.datetime` in UTC.
+    #[getter]
+    fn datetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        // Built from a timedelta so the milliseconds stay exact, abi3 has no datetime C API.
+        let datetime = py.import("datetime")?;
+        let utc = datetime.getattr("timezone")?.getattr("utc")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("tzinfo", utc)?;
+        let epoch = datetime
+            .getattr("datetime")?
+            .call((1970, 1, 1), Some(&kwargs))?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("milliseconds", self.0.get_timestamp())?;
+        let elapsed = datetime.getattr("timedelta")?.call((), Some(&kwargs))?;
+        epoch.add(elapsed)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SmallUid('{}')", self.0)
+    }
+
+    fn __int__(&self) -> u64 {
+        self.0.to_u64()
+    }
+}
+
+impl From<SmallUid> for PySmallUid {
+    fn from(value: SmallUid) -> Self {
+        PySmallUid(value)
+    }
+}
+
+impl From<PySmallUid> for SmallUid {
+    fn from(value: PySmallUid) -> Self {
+        value.0
+    }
+}
+
+/// Monotonic generator of SmallUids, exposed to Python as `small_uid.MonotonicGenerator`.
+///
+/// It is also an infinite iterator.
+#[pyclass(name = "MonotonicGenerator", module = "small_uid")]
+#[derive(Debug, Default)]
+pub struct PyMonotonicGenerator(MonotonicGenerator);
+
+#[pymethods]
+impl PyMonotonicGenerator {
+    #[new]
+    fn new() -> Self {
+        PyMonotonicGenerator(SmallUid::init_monotonic())
+    }
+
+    /// Generates the next SmallUid, always greater than the previous one.
+    ///
+    /// The GIL is released meanwhile, generating may sleep until the next millisecond.
+    fn generate(&mut self, py: Python<'_>) -> PySmallUid {
+        py.detach(|| PySmallUid(self.0.generate()))
+    }
+
+    /// Generates `count` SmallUids in increasing order, without holding the GIL.
+    fn generate_batch(&mut self, py: Python<'_>, count: usize) -> Vec<PySmallUid> {
+        py.detach(|| self.0.iter().take(count).map(PySmallUid).collect())
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PySmallUid {
+        self.generate(py)
+    }
+}
+
+/// Parses a base64-url encoded SmallUid.
+#[pyfunction]
+fn parse(value: &str) -> PyResult<PySmallUid> {
+    Ok(PySmallUid(SmallUid::try_from(value.to_string())?))
+}
+
+#[pymodule]
+fn small_uid(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySmallUid>()?;
+    m.add_class::<PyMonotonicGenerator>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add("SmallUidError", m.py().get_type::<SmallUidError>())?;
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::{
+    Error, SmallUid, SmallUidError,
+    checking::{MAX_RANDOM, MAX_TIMESTAMP, timestamp_check},
+    datetime::SmallUidTime,
+};
+
+impl SmallUid {
+    /// Returns the smallest SmallUid possible for the timestamp.
+    pub const fn min_for_timestamp(timestamp: u64) -> Result<SmallUid, Error> {
+        SmallUid::from_parts_checked(timestamp, 0)
+    }
+
+    /// Returns the largest SmallUid possible for the timestamp.
+    pub const fn max_for_timestamp(timestamp: u64) -> Result<SmallUid, Error> {
+        SmallUid::from_parts_checked(timestamp, MAX_RANDOM)
+    }
+}
+
+/// Inclusive range of every SmallUid generated within a time range.
+///
+/// It implements `RangeBounds<SmallUid>` so it can be used with `BTreeMap::range`,
+/// and `start()`/`end()` can be bound to a SQL `BETWEEN` clause.
+///
+/// ```
+/// use small_uid::{SmallUid, SmallUidRange};
+/// use std::collections::BTreeMap;
+///
+/// let mut rows = BTreeMap::new();
+/// rows.insert(SmallUid::from_timestamp(1_000), "a");
+/// rows.insert(SmallUid::from_timestamp(2_000), "b");
+/// rows.insert(SmallUid::from_timestamp(3_000), "c");
+///
+/// let range = SmallUidRange::from_timestamps(1_500..=3_000).unwrap();
+/// let found: Vec<&str> = rows.range(range).map(|(_, v)| *v).collect();
+/// assert_eq!(found, ["b", "c"]);
+/// ```
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmallUidRange {
+    start: SmallUid,
+    end: SmallUid,
+}
+
+impl SmallUidRange {
+    /// Creates the range of SmallUids generated within the range of timestamps in milliseconds.
+    ///
+    /// Fails if a bound is beyond the 44-bit timestamp range or if the range is empty.
+    pub fn from_timestamps<R: RangeBounds<u64>>(range: R) -> Result<SmallUidRange, Error> {
+        let start = match range.start_bound() {
+            Bound::Included(&timestamp) => SmallUid::min_for_timestamp(timestamp)?,
+            Bound::Excluded(&timestamp) => {
+                // Nothing follows the last timestamp, the range is empty
+                if timestamp_check(timestamp)? == MAX_TIMESTAMP {
+                    return Err(SmallUidError::InvalidRange);
+                }
+                SmallUid::min_for_timestamp(timestamp + 1)?
+            }
+            Bound::Unbounded => SmallUid(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&timestamp) => SmallUid::max_for_timestamp(timestamp)?,
+            Bound::Excluded(&timestamp) => match timestamp.checked_sub(1) {
+                Some(timestamp) => SmallUid::max_for_timestamp(timestamp)?,
+                None => return Err(SmallUidError::InvalidRange),
+            },
+            Bound::Unbounded => SmallUid(u64::MAX),
+        };
+        if start > end {
+            return Err(SmallUidError::InvalidRange);
+        }
+        Ok(SmallUidRange { start, end })
+    }
+
+    /// Creates the range of SmallUids generated within the range of any supported date and time type.
+    ///
+    /// Times are truncated to the millisecond. An excluded bound with a sub-millisecond part
+    /// becomes an included one, its millisecond still holds ids on the included side.
+    pub fn from_datetimes<T: SmallUidTime, R: RangeBounds<T>>(
+        range: R,
+    ) -> Result<SmallUidRange, Error> {
+        let start = map_bound(range.start_bound())?;
+        let end = map_bound(range.end_bound())?;
+        SmallUidRange::from_timestamps((start, end))
+    }
+
+    /// Returns the smallest SmallUid of the range.
+    pub fn start(&self) -> SmallUid {
+        self.start
+    }
+
+    /// Returns the largest SmallUid of the range.
+    pub fn end(&self) -> SmallUid {
+        self.end
+    }
+
+    /// Returns true if the SmallUid was generated within the range.
+    pub fn contains(&self, smalluid: &SmallUid) -> bool {
+        self.start <= *smalluid && *smalluid <= self.end
+    }
+
+    /// Returns the smallest and largest SmallUids of the range.
+    pub fn into_inner(self) -> (SmallUid, SmallUid) {
+        (self.start, self.end)
+    }
+}
+
+fn map_bound<T: SmallUidTime>(bound: Bound<&T>) -> Result<Bound<u64>, Error> {
+    Ok(match bound {
+        Bound::Included(datetime) => Bound::Included(datetime.to_unix_millis()?),
+        Bound::Excluded(datetime) => {
+            let millis = datetime.to_unix_millis()?;
+            if T::from_unix_millis(millis)? == *datetime {
+                Bound::Excluded(millis)
+            } else {
+                Bound::Included(millis)
+            }
+        }
+        Bound::Unbounded => Bound::Unbounded,
+    })
+}
+
+impl RangeBounds<SmallUid> for SmallUidRange {
+    fn start_bound(&self) -> Bound<&SmallUid> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&SmallUid> {
+        Bound::Included(&self.end)
+    }
+}
+
+impl From<SmallUidRange> for RangeInclusive<SmallUid> {
+    fn from(value: SmallUidRange) -> Self {
+        value.start..=value.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[cfg(target_arch = "wasm32")]
+    use web_time::{Duration, UNIX_EPOCH};
+
+    #[cfg(not(target_arch = "wasm32"))]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_min_max_for_timestamp() {
+        let min = SmallUid::min_for_timestamp(42).unwrap();
+        let max = SmallUid::max_for_timestamp(42).unwrap();
+        assert_eq!(min.get_timestamp(), 42);
+        assert_eq!(min.get_random(), 0);
+        assert_eq!(max.get_timestamp(), 42);
+        assert_eq!(max.get_random(), MAX_RANDOM);
+        assert_eq!(
+            max.to_u64() + 1,
+            SmallUid::min_for_timestamp(43).unwrap().to_u64()
+        );
+        assert!(SmallUid::max_for_timestamp(MAX_TIMESTAMP).is_ok());
+        assert!(matches!(
+            SmallUid::min_for_timestamp(MAX_TIMESTAMP + 1),
+            Err(SmallUidError::TimestampLimit)
+        ));
+    }
+
+    #[test]
+    fn test_from_timestamps_bounds() {
+        let range = SmallUidRange::from_timestamps(10..20).unwrap();
+        assert_eq!(range.start(), SmallUid::min_for_timestamp(10).unwrap());
+        assert_eq!(range.end(), SmallUid::max_for_timestamp(19).unwrap());
+
+        let range =
+            SmallUidRange::from_timestamps((Bound::Excluded(10), Bound::Included(20))).unwrap();
+        assert_eq!(range.start(), SmallUid::min_for_timestamp(11).unwrap());
+        assert_eq!(range.end(), SmallUid::max_for_timestamp(20).unwrap());
+
+        let range = SmallUidRange::from_timestamps(..).unwrap();
+        assert_eq!(range.into_inner(), (SmallUid(0), SmallUid(u64::MAX)));
+
+        let range = SmallUidRange::from_timestamps(5..=5).unwrap();
+        assert!(range.contains(&SmallUid::from_parts(5, 123)));
+        assert!(!range.contains(&SmallUid::from_parts(4, MAX_RANDOM)));
+        assert!(!range.contains(&SmallUid::from_parts(6, 0)));
+    }
+
+    #[test]
+    fn test_from_timestamps_invalid() {
+        assert!(matches!(
+            SmallUidRange::from_timestamps((Bound::Included(20), Bound::Excluded(10))),
+            Err(SmallUidError::InvalidRange)
+        ));
+        assert!(matches!(
+            SmallUidRange::from_timestamps(10..10),
+            Err(SmallUidError::InvalidRange)
+        ));
+        assert!(matches!(
+            SmallUidRange::from_timestamps(..0),
+            Err(SmallUidError::InvalidRange)
+        ));
+        assert!(matches!(
+            SmallUidRange::from_timestamps(0..=MAX_TIMESTAMP + 1),
+            Err(SmallUidError::TimestampLimit)
+        ));
+        assert!(matches!(
+            SmallUidRange::from_timestamps((Bound::Excluded(MAX_TIMESTAMP), Bound::Unbounded)),
+            Err(SmallUidError::InvalidRange)
+        ));
+        assert!(matches!(
+            SmallUidRange::from_timestamps((Bound::Excluded(MAX_TIMESTAMP + 1), Bound::Unbounded)),
+            Err(SmallUidError::TimestampLimit)
+        ));
+    }
+
+    #[test]
+    fn test_btreemap_range() {
+        let mut map = BTreeMap::new();
+        for timestamp in 0..100 {
+            map.insert(SmallUid::from_parts(timestamp, 0), timestamp);
+            map.insert(SmallUid::from_parts(timestamp, MAX_RANDOM), timestamp);
+        }
+        let range = SmallUidRange::from_timestamps(10..20).unwrap();
+        let found: Vec<u64> = map.range(range).map(|(_, v)| *v).collect();
+        assert_eq!(found.len(), 20);
+        assert!(found.iter().all(|v| (10..20).contains(v)));
+    }
+
+    #[test]
+    fn test_from_datetimes() {
+        let start = UNIX_EPOCH + Duration::from_millis(1_000);
+        let end = UNIX_EPOCH + Duration::from_millis(2_000);
+        let range = SmallUidRange::from_datetimes(start..end).unwrap();
+        assert_eq!(range, SmallUidRange::from_timestamps(1_000..2_000).unwrap());
+        let inclusive: RangeInclusive<SmallUid> = range.into();
+        assert_eq!(*inclusive.start(), range.start());
+        assert_eq!(*inclusive.end(), range.end());
+    }
+
+    #[test]
+    fn test_from_datetimes_sub_millisecond_excluded() {
+        let start = UNIX_EPOCH + Duration::from_micros(1_000_500);
+        let end = UNIX_EPOCH + Duration::from_micros(2_000_500);
+        let range = SmallUidRange::from_datetimes(start..end).unwrap();
+        assert_eq!(
+            range,
+            SmallUidRange::from_timestamps(1_000..=2_000).unwrap()
+        );
+        let range = SmallUidRange::from_datetimes((Bound::Excluded(start), Bound::Unbounded));
+        assert_eq!(
+            range.unwrap().start(),
+            SmallUid::min_for_timestamp(1_000).unwrap()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::num::NonZeroU64;
+
+use crate::{
+    Error, SmallUid,
+    checking::timestamp_check,
+    generation::{random_gen, timestamp_gen},
+};
+
+/// Size of one tick of the 44-bit timestamp field.
+///
+/// The layout stays 44-bit timestamp followed by 20 random bits, a coarser resolution counts
+/// larger ticks in the timestamp field, extending the range of representable dates. Ids of
+/// different resolutions are not distinguishable, an application must stick to one.
+///
+/// | Resolution | Last representable date | Collision odds  |
+/// | ---------- | :---------------------: | :-------------: |
+/// | 1 ms       |        year 2527        |  1,024 _/ ms_   |
+/// | 10 ms      |        year 7544        | 1,024 _/ 10 ms_ |
+/// | 1 s        |       year 559444       |   1,024 _/ s_   |
+///
+/// Collision odds are the number of ids generated within one tick before the first expected
+/// collision, `sqrt(2^20)`, the same for every resolution but spread over a longer tick.
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Resolution {
+    tick_ms: NonZeroU64,
+}
+
+impl Resolution {
+    pub const MILLISECOND: Resolution = Resolution {
+        tick_ms: NonZeroU64::MIN,
+    };
+    pub const TEN_MILLISECONDS: Resolution = Resolution {
+        tick_ms: NonZeroU64::new(10).unwrap(),
+    };
+    pub const SECOND: Resolution = Resolution {
+        tick_ms: NonZeroU64::new(1_000).unwrap(),
+    };
+
+    /// Creates a resolution with a custom tick in milliseconds, or None for a zero tick.
+    pub const fn from_millis(tick_ms: u64) -> Option<Resolution> {
+        match NonZeroU64::new(tick_ms) {
+            Some(tick_ms) => Some(Resolution { tick_ms }),
+            None => None,
+        }
+    }
+
+    /// Returns the tick in milliseconds.
+    pub const fn tick_millis(&self) -> u64 {
+        self.tick_ms.get()
+    }
+
+    /// Converts milliseconds since the unix epoch to ticks, rounding down.
+    pub const fn ticks_from_millis(&self, millis: u64) -> u64 {
+        millis / self.tick_ms.get()
+    }
+
+    /// Converts ticks to milliseconds since the unix epoch, or None on overflow.
+    pub const fn millis_from_ticks(&self, ticks: u64) -> Option<u64> {
+        ticks.checked_mul(self.tick_ms.get())
+    }
+
+    /// Generates the current timestamp in ticks, checked against the 44-bit range.
+    pub fn timestamp_gen(&self) -> Result<u64, Error> {
+        timestamp_check(self.ticks_from_millis(timestamp_gen()?))
+    }
+}
+
+impl Default for Resolution {
+    fn default() -> Self {
+        Resolution::MILLISECOND
+    }
+}
+
+impl SmallUid {
+    /// Creates a new small unique identifier with a timestamp of the given resolution.
+    pub fn new_with_resolution(resolution: Resolution) -> SmallUid {
+        SmallUid::from_parts(resolution.timestamp_gen().unwrap(), random_gen())
+    }
+
+    /// Creates a SmallUid from milliseconds since the unix epoch and a random number,
+    /// storing the timestamp in ticks of the given resolution.
+    pub fn from_millis_with_resolution(
+        millis: u64,
+        resolution: Resolution,
+    ) -> Result<SmallUid, Error> {
+        SmallUid::from_parts_checked(resolution.ticks_from_millis(millis), random_gen())
+    }
+
+    /// Returns the timestamp in milliseconds since the unix epoch, reading the timestamp field
+    /// as ticks of the given resolution. Returns None on overflow.
+    pub fn get_millis_with_resolution(&self, resolution: Resolution) -> Option<u64> {
+        resolution.millis_from_ticks(self.get_timestamp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SmallUidError, checking::MAX_TIMESTAMP};
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Resolution::MILLISECOND.ticks_from_millis(1_234), 1_234);
+        assert_eq!(Resolution::TEN_MILLISECONDS.ticks_from_millis(1_239), 123);
+        assert_eq!(Resolution::SECOND.ticks_from_millis(1_999), 1);
+        assert_eq!(Resolution::SECOND.millis_from_ticks(2), Some(2_000));
+        assert_eq!(Resolution::SECOND.millis_from_ticks(u64::MAX), None);
+        assert_eq!(Resolution::from_millis(0), None);
+        assert_eq!(Resolution::from_millis(250).unwrap().tick_millis(), 250);
+        assert_eq!(Resolution::default(), Resolution::MILLISECOND);
+    }
+
+    #[test]
+    fn test_new_with_resolution() {
+        let now = timestamp_gen().unwrap();
+        let uid = SmallUid::new_with_resolution(Resolution::SECOND);
+        let millis = uid.get_millis_with_resolution(Resolution::SECOND).unwrap();
+        assert!(millis <= now + 1_000 && now < millis + 2_000);
+        assert_eq!(millis % 1_000, 0);
+    }
+
+    #[test]
+    fn test_from_millis_with_resolution() {
+        let uid =
+            SmallUid::from_millis_with_resolution(1_700_000_000_123, Resolution::TEN_MILLISECONDS)
+                .unwrap();
+        assert_eq!(uid.get_timestamp(), 170_000_000_012);
+        assert_eq!(
+            uid.get_millis_with_resolution(Resolution::TEN_MILLISECONDS),
+            Some(1_700_000_000_120)
+        );
+
+        // Seconds extend the range beyond the millisecond limit
+        let far = (MAX_TIMESTAMP + 1) * 10;
+        assert!(matches!(
+            SmallUid::from_millis_with_resolution(far, Resolution::MILLISECOND),
+            Err(SmallUidError::TimestampLimit)
+        ));
+        assert!(SmallUid::from_millis_with_resolution(far, Resolution::SECOND).is_ok());
+    }
+
+    #[test]
+    fn test_monotonic_with_resolution() {
+        let mut generator = SmallUid::init_monotonic_with_resolution(Resolution::SECOND);
+        let uids = generator.generate_batch(100);
+        for i in 1..uids.len() {
+            assert!(
+                uids[i] > uids[i - 1],
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+        let now = Resolution::SECOND.timestamp_gen().unwrap();
+        assert!(uids[0].get_timestamp() <= now && now <= uids[0].get_timestamp() + 1);
+    }
+}
@@ -0,0 +1,101 @@
+//! rusqlite `ToSql` and `FromSql` implementations and SQL functions.
+//!
+//! A SmallUid is bound as an `INTEGER` through [`SmallUid::to_i64_sortable`], so `ORDER BY`
+//! and `BETWEEN` follow the SmallUid order. It is read back from an `INTEGER` or from its
+//! base64-url `TEXT`.
+//!
+//! [`register_functions`](crate::rusqlite::register_functions) adds these SQL functions to a connection:
+//!
+//! | Function                    | Result                                                  |
+//! | --------------------------- | ------------------------------------------------------- |
+//! | `small_uid()`               | a new id as an `INTEGER`                                |
+//! | `small_uid_monotonic()`     | a new id, greater than every id it returned before      |
+//! | `small_uid_timestamp(id)`   | milliseconds since the unix epoch                       |
+//! | `small_uid_to_text(id)`     | the base64-url string                                   |
+//! | `small_uid_from_text(text)` | the id as an `INTEGER`                                  |
+//!
+//! The decoding functions take an `INTEGER` or `TEXT` id and return `NULL` for `NULL`.
+//!
+//! The `small_uid_sqlite` crate in `sqlite/` builds them as a SQLite loadable extension.
+//!
+//! ```
+//! use rusqlite::Connection;
+//! use small_uid::SmallUid;
+//!
+//! let connection = Connection::open_in_memory().unwrap();
+//! small_uid::rusqlite::register_functions(&connection).unwrap();
+//! connection
+//!     .execute_batch("CREATE TABLE events (id INTEGER PRIMARY KEY DEFAULT (small_uid()))")
+//!     .unwrap();
+//! connection.execute("INSERT INTO events DEFAULT VALUES", ()).unwrap();
+//! let id: SmallUid = connection
+//!     .query_row("SELECT id FROM events", (), |row| row.get(0))
+//!     .unwrap();
+//! ```
+
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use ::rusqlite::{
+    Connection, Error, Result,
+    functions::{Context, FunctionFlags},
+    types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef},
+};
+
+use crate::{MonotonicGenerator, SmallUid, generation::generate};
+
+/// Generator behind `small_uid_monotonic()`, shared by every connection of the process.
+static MONOTONIC: LazyLock<Mutex<MonotonicGenerator>> = LazyLock::new(Mutex::default);
+
+impl ToSql for SmallUid {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(self.to_i64_sortable().into())
+    }
+}
+
+impl FromSql for SmallUid {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(value) => Ok(SmallUid::from_i64_sortable(value)),
+            ValueRef::Text(text) => {
+                let text = std::str::from_utf8(text).map_err(FromSqlError::other)?;
+                SmallUid::try_from(text.to_string()).map_err(FromSqlError::other)
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// Reads the SmallUid argument of a decoding function, `None` for `NULL`.
+fn argument(context: &Context<'_>) -> Result<Option<SmallUid>> {
+    context.get(0)
+}
+
+/// Registers the `small_uid*` SQL functions on the connection.
+pub fn register_functions(connection: &Connection) -> Result<()> {
+    let volatile = FunctionFlags::SQLITE_UTF8;
+    let deterministic = FunctionFlags::SQLITE_UTF8
+        | FunctionFlags::SQLITE_DETERMINISTIC
+        | FunctionFlags::SQLITE_INNOCUOUS;
+    connection.create_scalar_function("small_uid", 0, volatile, |_| {
+        let uid = generate().map_err(|err| Error::UserFunctionError(err.into()))?;
+        Ok(uid.to_i64_sortable())
+    })?;
+    connection.create_scalar_function("small_uid_monotonic", 0, volatile, |_| {
+        let mut generator = MONOTONIC.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(generator.generate().to_i64_sortable())
+    })?;
+    connection.create_scalar_function("small_uid_timestamp", 1, deterministic, |context| {
+        Ok(argument(context)?.map(|uid| uid.get_timestamp() as i64))
+    })?;
+    connection.create_scalar_function("small_uid_to_text", 1, deterministic, |context| {
+        Ok(argument(context)?.map(|uid| uid.to_string()))
+    })?;
+    connection.create_scalar_function("small_uid_from_text", 1, deterministic, |context| {
+        let Some(text) = context.get::<Option<String>>(0)? else {
+            return Ok(None);
+        };
+        let uid = SmallUid::try_from(text).map_err(|err| Error::UserFunctionError(err.into()))?;
+        Ok(Some(uid.to_i64_sortable()))
+    })?;
+    Ok(())
+}
@@ -0,0 +1,211 @@
+//! Serde representations of SmallUid.
+//!
+//! By default a SmallUid is serialized as its base64-url string in human readable formats
+//! such as JSON, and as a `u64` in compact formats. Deserialization accepts the string,
+//! any integer fitting in a `u64` and 8 big-endian bytes, whatever the format.
+//!
+//! The modules below force one representation for a single field:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use small_uid::SmallUid;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "small_uid::serde::as_u64")]
+//!     id: SmallUid,
+//!     #[serde(with = "small_uid::serde::as_string")]
+//!     parent: SmallUid,
+//! }
+//! ```
+
+use std::fmt;
+
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+};
+
+use crate::SmallUid;
+
+impl Serialize for SmallUid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            as_string::serialize(self, serializer)
+        } else {
+            as_u64::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SmallUid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SmallUidVisitor)
+        } else {
+            deserializer.deserialize_u64(SmallUidVisitor)
+        }
+    }
+}
+
+/// Accepts every representation of a SmallUid.
+struct SmallUidVisitor;
+
+impl<'de> Visitor<'de> for SmallUidVisitor {
+    type Value = SmallUid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a base64-url string, an unsigned 64-bit integer or 8 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<SmallUid, E> {
+        SmallUid::try_from(value.to_string()).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<SmallUid, E> {
+        Ok(SmallUid(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<SmallUid, E> {
+        SmallUid::from_i64(value).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<SmallUid, E> {
+        let bytes: [u8; 8] = value
+            .try_into()
+            .map_err(|_| E::invalid_length(value.len(), &self))?;
+        Ok(SmallUid(u64::from_be_bytes(bytes)))
+    }
+
+    /// Bytes written by formats without a bytes type, such as JSON arrays.
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<SmallUid, A::Error> {
+        let mut bytes = [0u8; 8];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(9, &self));
+        }
+        Ok(SmallUid(u64::from_be_bytes(bytes)))
+    }
+}
+
+/// Serializes as the base64-url string, deserializes from any representation.
+pub mod as_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &SmallUid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SmallUid, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SmallUidVisitor)
+        } else {
+            deserializer.deserialize_str(SmallUidVisitor)
+        }
+    }
+}
+
+/// Serializes as a `u64`, deserializes from any representation.
+pub mod as_u64 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &SmallUid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SmallUid, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SmallUidVisitor)
+        } else {
+            deserializer.deserialize_u64(SmallUidVisitor)
+        }
+    }
+}
+
+/// Serializes as 8 big-endian bytes, deserializes from any representation.
+pub mod as_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &SmallUid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.0.to_be_bytes())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SmallUid, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SmallUidVisitor)
+        } else {
+            deserializer.deserialize_bytes(SmallUidVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use serde_test::{Configure, Token, assert_de_tokens, assert_tokens};
+
+    use crate::SmallUid;
+
+    #[test]
+    fn test_readable_is_string() {
+        let uid = SmallUid(0x1234_5678_9ABC_DEF0);
+        assert_tokens(&uid.readable(), &[Token::Str("EjRWeJq83vA")]);
+        assert_eq!(serde_json::to_string(&uid).unwrap(), format!("\"{}\"", uid));
+    }
+
+    #[test]
+    fn test_compact_is_u64() {
+        let uid = SmallUid(u64::MAX);
+        assert_tokens(&uid.compact(), &[Token::U64(u64::MAX)]);
+    }
+
+    #[test]
+    fn test_readable_accepts_every_form() {
+        let uid = SmallUid::new();
+        let from_string: SmallUid = serde_json::from_str(&format!("\"{}\"", uid)).unwrap();
+        let from_number: SmallUid = serde_json::from_str(&uid.to_u64().to_string()).unwrap();
+        assert_eq!(from_string, uid);
+        assert_eq!(from_number, uid);
+        assert_de_tokens(
+            &SmallUid(258).readable(),
+            &[Token::Bytes(&[0, 0, 0, 0, 0, 0, 1, 2])],
+        );
+        assert!(serde_json::from_str::<SmallUid>("-1").is_err());
+        assert!(serde_json::from_str::<SmallUid>("\"short\"").is_err());
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(with = "crate::serde::as_u64")]
+        id: SmallUid,
+        #[serde(with = "crate::serde::as_string")]
+        parent: SmallUid,
+        #[serde(with = "crate::serde::as_bytes")]
+        raw: SmallUid,
+    }
+
+    #[test]
+    fn test_field_overrides() {
+        let row = Row {
+            id: SmallUid(1),
+            parent: SmallUid(2),
+            raw: SmallUid(3),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "{{\"id\":1,\"parent\":\"{}\",\"raw\":[0,0,0,0,0,0,0,3]}}",
+                SmallUid(2)
+            )
+        );
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        let swapped = format!("{{\"id\":\"{}\",\"parent\":2,\"raw\":3}}", SmallUid(1));
+        assert_eq!(serde_json::from_str::<Row>(&swapped).unwrap(), row);
+    }
+}
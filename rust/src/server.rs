@@ -0,0 +1,169 @@
+//! HTTP service issuing SmallUids, for applications that cannot embed the crate.
+//!
+//! Every request is served by one shared [`MonotonicGenerator`](crate::MonotonicGenerator),
+//! so all clients get globally ordered ids. The `small-uid-server` binary serves
+//! [`router`](crate::server::router) on localhost:
+//!
+//! | Route               | Response                                                          |
+//! | ------------------- | ----------------------------------------------------------------- |
+//! | `GET /id`           | one id as text                                                    |
+//! | `GET /ids?n=`       | `n` ids as text, one per line, at most [`MAX_BATCH`](crate::server::MAX_BATCH) |
+//! | `GET /decode/{id}`  | [`SmallUidInfo`](crate::SmallUidInfo) as JSON                     |
+//! | `GET /health`       | `ok`                                                              |
+//! | `GET /metrics`      | counters in the Prometheus text format                            |
+//!
+//! Invalid input is answered with `400 Bad Request` and the `SmallUidError` message, a failure
+//! to generate, such as a clock before the unix epoch, with `500 Internal Server Error`.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Instant,
+};
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State, rejection::QueryRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::Deserialize;
+use tokio::net::TcpListener;
+
+use crate::{Error, SmallUid, SmallUidInfo, shared::SharedGenerator};
+
+pub use crate::shared::MAX_BATCH;
+
+/// Shared state of the service: the generator and the metrics counters.
+#[derive(Debug)]
+pub struct ServerState {
+    generator: SharedGenerator,
+    started: Instant,
+    requests: AtomicU64,
+    issued: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl Default for ServerState {
+    fn default() -> Self {
+        ServerState {
+            generator: SharedGenerator::default(),
+            started: Instant::now(),
+            requests: AtomicU64::new(0),
+            issued: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+        }
+    }
+}
+
+impl ServerState {
+    async fn generate(&self, count: usize) -> Result<Vec<SmallUid>, Error> {
+        let uids = self.generator.generate(count).await?;
+        self.issued.fetch_add(count as u64, Ordering::Relaxed);
+        Ok(uids)
+    }
+
+    fn bad_request(&self, message: String) -> Response {
+        self.error(StatusCode::BAD_REQUEST, message)
+    }
+
+    fn internal_error(&self, err: Error) -> Response {
+        self.error(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+    }
+
+    fn error(&self, status: StatusCode, message: String) -> Response {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+        (status, message).into_response()
+    }
+}
+
+/// Builds the routes of the service over a shared state.
+pub fn router(state: Arc<ServerState>) -> Router {
+    Router::new()
+        .route("/id", get(id))
+        .route("/ids", get(ids))
+        .route("/decode/{id}", get(decode))
+        .route("/health", get(health))
+        .route("/metrics", get(metrics))
+        .with_state(state)
+}
+
+/// Serves the routes on the listener until the future is dropped or ctrl-c is received.
+pub async fn serve(listener: TcpListener) -> std::io::Result<()> {
+    axum::serve(listener, router(Arc::default()))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+}
+
+async fn id(State(state): State<Arc<ServerState>>) -> Response {
+    state.requests.fetch_add(1, Ordering::Relaxed);
+    match state.generate(1).await {
+        Ok(uids) => uids[0].to_string().into_response(),
+        Err(err) => state.internal_error(err),
+    }
+}
+
+#[derive(Deserialize)]
+struct IdsQuery {
+    n: Option<usize>,
+}
+
+async fn ids(
+    State(state): State<Arc<ServerState>>,
+    query: Result<Query<IdsQuery>, QueryRejection>,
+) -> Response {
+    state.requests.fetch_add(1, Ordering::Relaxed);
+    let query = match query {
+        Ok(Query(query)) => query,
+        Err(rejection) => return state.bad_request(rejection.body_text()),
+    };
+    let count = query.n.unwrap_or(1);
+    if count == 0 || count > MAX_BATCH {
+        return state.bad_request(format!("n must be between 1 and {}", MAX_BATCH));
+    }
+    match state.generate(count).await {
+        Ok(uids) => {
+            let mut body = String::with_capacity(count * 12);
+            for uid in uids {
+                body.push_str(&uid.to_string());
+                body.push('\n');
+            }
+            body.into_response()
+        }
+        Err(err) => state.internal_error(err),
+    }
+}
+
+async fn decode(State(state): State<Arc<ServerState>>, Path(id): Path<String>) -> Response {
+    state.requests.fetch_add(1, Ordering::Relaxed);
+    match SmallUid::try_from(id) {
+        Ok(uid) => Json::<SmallUidInfo>(uid.inspect()).into_response(),
+        Err(err) => state.bad_request(err.to_string()),
+    }
+}
+
+async fn health() -> &'static str {
+    "ok"
+}
+
+async fn metrics(State(state): State<Arc<ServerState>>) -> String {
+    format!(
+        "# TYPE small_uid_requests_total counter\n\
+         small_uid_requests_total {}\n\
+         # TYPE small_uid_ids_issued_total counter\n\
+         small_uid_ids_issued_total {}\n\
+         # TYPE small_uid_errors_total counter\n\
+         small_uid_errors_total {}\n\
+         # TYPE small_uid_uptime_seconds gauge\n\
+         small_uid_uptime_seconds {}\n",
+        state.requests.load(Ordering::Relaxed),
+        state.issued.load(Ordering::Relaxed),
+        state.errors.load(Ordering::Relaxed),
+        state.started.elapsed().as_secs(),
+    )
+}
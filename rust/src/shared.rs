@@ -0,0 +1,38 @@
+use std::{sync::Mutex, time::Duration};
+
+use crate::{Error, MonotonicGenerator, SmallUid};
+
+/// Largest number of ids the services issue in one request.
+pub const MAX_BATCH: usize = 10_000;
+
+/// Monotonic generator shared by every request of a service, so all clients get globally
+/// ordered ids.
+#[derive(Debug, Default)]
+pub(crate) struct SharedGenerator {
+    generator: Mutex<MonotonicGenerator>,
+}
+
+impl SharedGenerator {
+    /// Generates `count` monotonic ids, waiting without holding the lock when the counter
+    /// of the current millisecond is exhausted.
+    pub(crate) async fn generate(&self, count: usize) -> Result<Vec<SmallUid>, Error> {
+        let mut uids = Vec::with_capacity(count);
+        while uids.len() < count {
+            {
+                let mut generator = self.generator.lock().unwrap();
+                while uids.len() < count {
+                    match generator.try_generate() {
+                        Ok(uid) => uids.push(uid),
+                        Err(err) if err.is_exhausted() => break,
+                        Err(err) => return Err(err),
+                    }
+                }
+            }
+            if uids.len() < count {
+                // Delay until the next millisecond
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        }
+        Ok(uids)
+    }
+}
@@ -0,0 +1,158 @@
+//! sqlx `Type`, `Encode` and `Decode` implementations for Postgres, MySQL and SQLite.
+//!
+//! A SmallUid is stored as a 64-bit integer that sorts like the SmallUid, so `ORDER BY id`
+//! and `BETWEEN` over a [`SmallUidRange`](crate::SmallUidRange) behave as expected:
+//!
+//! | Backend  | Column            | Value                                        |
+//! | -------- | ----------------- | -------------------------------------------- |
+//! | Postgres | `BIGINT`          | [`SmallUid::to_i64_sortable`]                |
+//! | SQLite   | `INTEGER`         | [`SmallUid::to_i64_sortable`]                |
+//! | MySQL    | `BIGINT UNSIGNED` | the raw `u64`                                |
+//!
+//! Wrap the id in [`AsText`](crate::sqlx::AsText) to store its base64-url string in a text column instead.
+//!
+//! ```no_run
+//! # async fn run(pool: sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+//! use small_uid::{SmallUid, sqlx::AsText};
+//!
+//! let id = SmallUid::new();
+//! sqlx::query("INSERT INTO users (id, parent) VALUES (?, ?)")
+//!     .bind(id)
+//!     .bind(AsText(id))
+//!     .execute(&pool)
+//!     .await?;
+//! let (found,): (SmallUid,) = sqlx::query_as("SELECT id FROM users")
+//!     .fetch_one(&pool)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use ::sqlx::{
+    Database, Decode, Encode, MySql, Postgres, Sqlite, Type,
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgHasArrayType, PgTypeInfo},
+};
+
+use crate::SmallUid;
+
+/// Implements the traits for a signed backend, through [`SmallUid::to_i64_sortable`].
+macro_rules! impl_sortable {
+    ($db:ty) => {
+        impl Type<$db> for SmallUid {
+            fn type_info() -> <$db as Database>::TypeInfo {
+                <i64 as Type<$db>>::type_info()
+            }
+
+            fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                <i64 as Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl Encode<'_, $db> for SmallUid {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <$db as Database>::ArgumentBuffer,
+            ) -> Result<IsNull, BoxDynError> {
+                <i64 as Encode<$db>>::encode(self.to_i64_sortable(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, $db> for SmallUid {
+            fn decode(value: <$db as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                <i64 as Decode<$db>>::decode(value).map(SmallUid::from_i64_sortable)
+            }
+        }
+    };
+}
+
+impl_sortable!(Postgres);
+impl_sortable!(Sqlite);
+
+impl PgHasArrayType for SmallUid {
+    fn array_type_info() -> PgTypeInfo {
+        <i64 as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Type<MySql> for SmallUid {
+    fn type_info() -> <MySql as Database>::TypeInfo {
+        <u64 as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &<MySql as Database>::TypeInfo) -> bool {
+        <u64 as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, MySql> for SmallUid {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <MySql as Database>::ArgumentBuffer,
+    ) -> Result<IsNull, BoxDynError> {
+        <u64 as Encode<MySql>>::encode(self.0, buf)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for SmallUid {
+    fn decode(value: <MySql as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        <u64 as Decode<MySql>>::decode(value).map(SmallUid)
+    }
+}
+
+/// SmallUid stored as its 11-character base64-url string, on every backend.
+///
+/// Text columns do not sort like the SmallUids, keep an integer column for `ORDER BY`.
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AsText(pub SmallUid);
+
+impl From<SmallUid> for AsText {
+    fn from(value: SmallUid) -> Self {
+        AsText(value)
+    }
+}
+
+impl From<AsText> for SmallUid {
+    fn from(value: AsText) -> Self {
+        value.0
+    }
+}
+
+impl<DB: Database> Type<DB> for AsText
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for AsText
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <String as Encode<DB>>::encode(self.0.to_string(), buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for AsText
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&str as Decode<DB>>::decode(value)?;
+        Ok(AsText(SmallUid::try_from(text.to_string())?))
+    }
+}
+
+impl PgHasArrayType for AsText {
+    fn array_type_info() -> PgTypeInfo {
+        <String as PgHasArrayType>::array_type_info()
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    pin::Pin,
+    sync::{Condvar, Mutex, OnceLock, PoisonError},
+    task::{Context, Poll, Waker},
+    thread::{self, sleep},
+    time::Duration,
+};
+
+use futures_core::{FusedStream, Stream};
+
+use crate::{Error, iter::Generator};
+
+/// Infinite stream of ids, returned by `stream()` on the generators.
+///
+/// When the current millisecond is exhausted the stream returns `Pending` and a timer thread,
+/// shared by every stream, wakes it once the next millisecond starts, so it never blocks the
+/// executor.
+/// Targets without threads, such as `wasm32-unknown-unknown`, wake it right away instead
+/// and the executor polls it in a loop until then.
+///
+/// # Panics
+///
+/// Polling panics when the clock fails, is before the unix epoch or is past the last
+/// timestamp a SmallUid can hold. Use [`TryGeneratorStream`], from `try_stream()`, to
+/// receive those errors instead.
+#[derive(Debug)]
+pub struct GeneratorStream<'a, G: Generator> {
+    generator: &'a mut G,
+}
+
+impl<'a, G: Generator> GeneratorStream<'a, G> {
+    pub(crate) fn new(generator: &'a mut G) -> Self {
+        GeneratorStream { generator }
+    }
+}
+
+impl<G: Generator> Stream for GeneratorStream<'_, G> {
+    type Item = G::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<G::Item>> {
+        match self.get_mut().generator.try_generate() {
+            Ok(smalluid) => Poll::Ready(Some(smalluid)),
+            Err(err) if err.is_exhausted() => {
+                wake_next_millisecond(cx.waker());
+                Poll::Pending
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<G: Generator> FusedStream for GeneratorStream<'_, G> {
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+/// Wakers of exhausted streams, woken together by one timer thread a millisecond later.
+struct Timer {
+    wakers: Mutex<Vec<Waker>>,
+    pending: Condvar,
+}
+
+static TIMER: Timer = Timer {
+    wakers: Mutex::new(Vec::new()),
+    pending: Condvar::new(),
+};
+
+/// Whether the timer thread is running, false on targets without threads.
+static TIMER_THREAD: OnceLock<bool> = OnceLock::new();
+
+impl Timer {
+    fn run(&self) {
+        loop {
+            let mut wakers = self.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+            while wakers.is_empty() {
+                wakers = self
+                    .pending
+                    .wait(wakers)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+            drop(wakers);
+            sleep(Duration::from_millis(1));
+            let wakers =
+                std::mem::take(&mut *self.wakers.lock().unwrap_or_else(PoisonError::into_inner));
+            for waker in wakers {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Wakes the task once the next millisecond starts, or right away without threads.
+fn wake_next_millisecond(waker: &Waker) {
+    let started = *TIMER_THREAD.get_or_init(|| {
+        thread::Builder::new()
+            .name("small-uid-timer".to_string())
+            .spawn(|| TIMER.run())
+            .is_ok()
+    });
+    if !started {
+        waker.wake_by_ref();
+        return;
+    }
+    let mut wakers = TIMER.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+    if !wakers.iter().any(|pending| pending.will_wake(waker)) {
+        wakers.push(waker.clone());
+    }
+    TIMER.pending.notify_one();
+}
+
+/// Infinite stream of fallible ids, returned by `try_stream()` on the generators.
+///
+/// Exhaustion of the current millisecond and clock errors are yielded as `Err`,
+/// the stream can be polled again afterward.
+#[derive(Debug)]
+pub struct TryGeneratorStream<'a, G: Generator> {
+    generator: &'a mut G,
+}
+
+impl<'a, G: Generator> TryGeneratorStream<'a, G> {
+    pub(crate) fn new(generator: &'a mut G) -> Self {
+        TryGeneratorStream { generator }
+    }
+}
+
+impl<G: Generator> Stream for TryGeneratorStream<'_, G> {
+    type Item = Result<G::Item, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(Some(self.get_mut().generator.try_generate()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<G: Generator> FusedStream for TryGeneratorStream<'_, G> {
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use futures::{Stream, StreamExt, executor::block_on, task::noop_waker};
+
+    use super::TIMER;
+    use crate::{SmallUid, SmallUidError, checking::MAX_TIMESTAMP, timestamp_gen};
+
+    #[test]
+    fn test_stream_take_collect() {
+        let mut generator = SmallUid::init_monotonic();
+        let uids: Vec<SmallUid> = block_on(generator.stream().take(4096).collect());
+        assert_eq!(uids.len(), 4096);
+        for i in 1..uids.len() {
+            assert!(
+                uids[i] > uids[i - 1],
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_zip() {
+        let mut generator = SmallUid::init_distinct();
+        let names = futures::stream::iter(["a", "b", "c"]);
+        let pairs: Vec<(&str, SmallUid)> = block_on(names.zip(generator.stream()).collect());
+        assert_eq!(pairs.len(), 3);
+    }
+
+    #[test]
+    fn test_stream_waits_for_next_millisecond() {
+        let mut generator = SmallUid::init_monotonic();
+        let timestamp = timestamp_gen().unwrap();
+        generator.last_ms = timestamp;
+        generator.upper_counter = 0x3FF;
+        let uid = block_on(generator.stream().next()).unwrap();
+        assert!(uid.get_timestamp() > timestamp);
+    }
+
+    #[test]
+    fn test_exhausted_polls_share_one_wake() {
+        let mut generator = SmallUid::init_monotonic();
+        // A millisecond in the future stays exhausted
+        generator.last_ms = MAX_TIMESTAMP;
+        generator.upper_counter = 0x3FF;
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut stream = generator.stream();
+        for _ in 0..100 {
+            assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+        }
+        let wakers = TIMER.wakers.lock().unwrap();
+        assert!(
+            wakers
+                .iter()
+                .filter(|pending| pending.will_wake(&waker))
+                .count()
+                <= 1
+        );
+    }
+
+    #[test]
+    fn test_try_stream_reports_exhaustion() {
+        let mut generator = SmallUid::init_monotonic();
+        generator.last_ms = MAX_TIMESTAMP;
+        generator.upper_counter = 0x3FF;
+        let result = block_on(generator.try_stream().next());
+        assert!(matches!(
+            result,
+            Some(Err(SmallUidError::MonotonicCounterLimit))
+        ));
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{SmallUid, generation::random_gen, generation::timestamp_gen};
-use serde_json;
+use crate::{
+    SmallUid, SmallUidError,
+    checking::{MAX_RANDOM, MAX_TIMESTAMP, rng_size_check, timestamp_check},
+    generation::random_gen,
+    generation::timestamp_gen,
+};
 
 #[test]
 fn test_generation() {
@@ -57,6 +61,41 @@ fn test_batch_monotonic() {
     }
 }
 
+#[test]
+fn test_batch_uniqueness() {
+    let smalluids = SmallUid::batch_new(1 << 16);
+    assert_eq!(smalluids.len(), 1 << 16);
+    let mut seen = std::collections::HashSet::new();
+    for smalluid in smalluids.iter() {
+        assert!(seen.insert(smalluid), "Duplicate UID found in batch");
+    }
+    for i in 1..smalluids.len() {
+        assert!(smalluids[i].get_timestamp() >= smalluids[i - 1].get_timestamp());
+    }
+}
+
+#[test]
+fn test_fill() {
+    let mut smalluids = [SmallUid::default(); 2048];
+    SmallUid::fill(&mut smalluids);
+    let mut seen = std::collections::HashSet::new();
+    for smalluid in smalluids.iter() {
+        assert!(smalluid.get_timestamp() > 0);
+        assert!(seen.insert(smalluid), "Duplicate UID found in fill");
+    }
+}
+
+#[test]
+fn test_extend_into() {
+    let first = SmallUid::new();
+    let mut smalluids = vec![first];
+    SmallUid::extend_into(&mut smalluids, 100);
+    assert_eq!(smalluids.len(), 101);
+    assert_eq!(smalluids[0], first);
+    SmallUid::extend_into(&mut smalluids, 0);
+    assert_eq!(smalluids.len(), 101);
+}
+
 #[test]
 fn test_from_timestamp() {
     let timestamp = timestamp_gen().unwrap();
@@ -139,6 +178,135 @@ fn test_try_from() {
     let smalluid = SmallUid::try_from(uidstr.to_string()).unwrap();
     assert!(smalluid == uidstr);
 }
+
+#[test]
+fn test_timestamp_check_boundaries() {
+    assert_eq!(timestamp_check(0).unwrap(), 0);
+    assert_eq!(
+        timestamp_check(MAX_TIMESTAMP - 1).unwrap(),
+        MAX_TIMESTAMP - 1
+    );
+    assert_eq!(timestamp_check(MAX_TIMESTAMP).unwrap(), MAX_TIMESTAMP);
+    assert!(matches!(
+        timestamp_check(MAX_TIMESTAMP + 1),
+        Err(SmallUidError::TimestampLimit)
+    ));
+    assert!(matches!(
+        timestamp_check(u64::MAX),
+        Err(SmallUidError::TimestampLimit)
+    ));
+}
+
+#[test]
+fn test_rng_size_check_boundaries() {
+    assert_eq!(rng_size_check(0).unwrap(), 0);
+    assert_eq!(rng_size_check(MAX_RANDOM).unwrap(), MAX_RANDOM);
+    assert!(matches!(
+        rng_size_check(MAX_RANDOM + 1),
+        Err(SmallUidError::RandomSizeLimit)
+    ));
+}
+
+#[test]
+fn test_from_parts_checked_boundaries() {
+    let max = SmallUid::from_parts_checked(MAX_TIMESTAMP, MAX_RANDOM).unwrap();
+    assert_eq!(max, SmallUid(u64::MAX));
+    assert_eq!(max.get_timestamp(), MAX_TIMESTAMP);
+    assert_eq!(max.get_random(), MAX_RANDOM);
+
+    let min = SmallUid::from_parts_checked(0, 0).unwrap();
+    assert_eq!(min, SmallUid(0));
+
+    let last = SmallUid::from_parts_checked(MAX_TIMESTAMP, 0).unwrap();
+    let before_last = SmallUid::from_parts_checked(MAX_TIMESTAMP - 1, MAX_RANDOM).unwrap();
+    assert!(before_last < last);
+
+    assert!(matches!(
+        SmallUid::from_parts_checked(MAX_TIMESTAMP + 1, 0),
+        Err(SmallUidError::TimestampLimit)
+    ));
+    assert!(matches!(
+        SmallUid::from_parts_checked(1 << 63, 0),
+        Err(SmallUidError::TimestampLimit)
+    ));
+    assert!(matches!(
+        SmallUid::from_parts_checked(0, MAX_RANDOM + 1),
+        Err(SmallUidError::RandomSizeLimit)
+    ));
+}
+
+#[test]
+fn test_from_parts_checked_const() {
+    const MAX: SmallUid = match SmallUid::from_parts_checked(MAX_TIMESTAMP, MAX_RANDOM) {
+        Ok(uid) => uid,
+        Err(_) => panic!("max parts should be valid"),
+    };
+    assert_eq!(MAX.to_u64(), u64::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_from_parts_timestamp_overflow() {
+    SmallUid::from_parts(MAX_TIMESTAMP + 1, 0);
+}
+
+#[test]
+#[should_panic]
+fn test_from_timestamp_overflow() {
+    SmallUid::from_timestamp(1 << 44);
+}
+
+#[test]
+#[should_panic]
+fn test_from_random_overflow() {
+    SmallUid::from_random(MAX_RANDOM + 1);
+}
+
+#[test]
+#[should_panic]
+fn test_generate_full_timestamp_overflow() {
+    let mut generator = SmallUid::init_monotonic();
+    generator.generate_full(MAX_TIMESTAMP + 1);
+}
+
+#[test]
+fn test_i64_sortable_order() {
+    let uids = [
+        SmallUid(0),
+        SmallUid(1),
+        SmallUid::new(),
+        SmallUid(i64::MAX as u64),
+        SmallUid(1 << 63),
+        SmallUid(u64::MAX - 1),
+        SmallUid(u64::MAX),
+    ];
+    assert_eq!(uids[0].to_i64_sortable(), i64::MIN);
+    assert_eq!(uids[6].to_i64_sortable(), i64::MAX);
+    for i in 1..uids.len() {
+        assert!(uids[i - 1] < uids[i]);
+        assert!(uids[i - 1].to_i64_sortable() < uids[i].to_i64_sortable());
+    }
+    for uid in uids {
+        assert_eq!(SmallUid::from_i64_sortable(uid.to_i64_sortable()), uid);
+    }
+}
+
+#[test]
+fn test_i64_plain() {
+    let uid = SmallUid::new();
+    assert_eq!(uid.to_i64().unwrap() as u64, uid.to_u64());
+    assert_eq!(SmallUid::from_i64(uid.to_i64().unwrap()).unwrap(), uid);
+    assert_eq!(i64::try_from(SmallUid(i64::MAX as u64)).unwrap(), i64::MAX);
+    assert!(matches!(
+        SmallUid(1 << 63).to_i64(),
+        Err(SmallUidError::I64Range)
+    ));
+    assert!(matches!(
+        SmallUid::try_from(-1i64),
+        Err(SmallUidError::I64Range)
+    ));
+}
+
 #[cfg(feature = "serde")]
 mod serde_tests {
     use super::*;
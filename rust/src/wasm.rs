@@ -0,0 +1,146 @@
+// JavaScript classes exported with wasm-bindgen.
+//
+// The Rust types are wrapped rather than exported directly so their JS names and methods
+// follow JavaScript conventions without leaking into the Rust API.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Error, MonotonicGenerator, SmallUid};
+
+/// A 64-bit sortable unique identifier, exported to JavaScript as `SmallUid`.
+#[wasm_bindgen(js_name = SmallUid)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmSmallUid(SmallUid);
+
+#[wasm_bindgen(js_class = SmallUid)]
+impl WasmSmallUid {
+    /// Creates a new SmallUid.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmSmallUid {
+        WasmSmallUid(SmallUid::new())
+    }
+
+    /// Parses a base64-url encoded SmallUid.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(value: &str) -> Result<WasmSmallUid, JsError> {
+        Ok(WasmSmallUid(SmallUid::try_from(value.to_string())?))
+    }
+
+    /// Creates a SmallUid from its 64-bit value.
+    #[wasm_bindgen(js_name = fromBigInt)]
+    pub fn from_big_int(value: u64) -> WasmSmallUid {
+        WasmSmallUid(SmallUid(value))
+    }
+
+    /// Returns the base64-url encoding.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the timestamp in milliseconds since the unix epoch, exact as a JS number.
+    pub fn timestamp(&self) -> f64 {
+        self.0.get_timestamp() as f64
+    }
+
+    /// Returns the 20 random bits.
+    pub fn random(&self) -> u32 {
+        self.0.get_random() as u32
+    }
+
+    /// Returns the 64-bit value as a BigInt.
+    #[wasm_bindgen(js_name = toBigInt)]
+    pub fn to_big_int(&self) -> u64 {
+        self.0.to_u64()
+    }
+}
+
+impl Default for WasmSmallUid {
+    fn default() -> Self {
+        WasmSmallUid::new()
+    }
+}
+
+impl From<SmallUid> for WasmSmallUid {
+    fn from(value: SmallUid) -> Self {
+        WasmSmallUid(value)
+    }
+}
+
+impl From<WasmSmallUid> for SmallUid {
+    fn from(value: WasmSmallUid) -> Self {
+        value.0
+    }
+}
+
+/// Monotonic generator of SmallUids, exported to JavaScript as `MonotonicGenerator`.
+#[wasm_bindgen(js_name = MonotonicGenerator)]
+#[derive(Debug, Default)]
+pub struct WasmMonotonicGenerator(MonotonicGenerator);
+
+#[wasm_bindgen(js_class = MonotonicGenerator)]
+impl WasmMonotonicGenerator {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmMonotonicGenerator {
+        WasmMonotonicGenerator(SmallUid::init_monotonic())
+    }
+
+    /// Generates the next SmallUid, always greater than the previous one.
+    pub fn generate(&mut self) -> Result<WasmSmallUid, JsError> {
+        Ok(WasmSmallUid(self.next_uid()?))
+    }
+
+    /// Generates `count` SmallUids in increasing order.
+    #[wasm_bindgen(js_name = generateBatch)]
+    pub fn generate_batch(&mut self, count: usize) -> Result<Vec<WasmSmallUid>, JsError> {
+        let mut uids = Vec::with_capacity(count);
+        for _ in 0..count {
+            uids.push(WasmSmallUid(self.next_uid()?));
+        }
+        Ok(uids)
+    }
+}
+
+impl WasmMonotonicGenerator {
+    // `std::thread::sleep` is not available in browsers, so counter exhaustion spins until
+    // the next millisecond instead of sleeping.
+    fn next_uid(&mut self) -> Result<SmallUid, Error> {
+        loop {
+            match self.0.try_generate() {
+                Err(err) if err.is_exhausted() => continue,
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_uid_accessors() {
+        let uid = SmallUid::from_parts(1_700_000_000_000, 0xABCDE);
+        let wasm = WasmSmallUid::from(uid);
+        assert_eq!(wasm.timestamp(), 1_700_000_000_000.0);
+        assert_eq!(wasm.random(), 0xABCDE);
+        assert_eq!(wasm.to_big_int(), uid.to_u64());
+        assert_eq!(wasm.to_js_string(), uid.to_string());
+        assert_eq!(WasmSmallUid::from_big_int(uid.to_u64()), wasm);
+        assert_eq!(WasmSmallUid::from_string(&uid.to_string()).unwrap(), wasm);
+    }
+
+    #[test]
+    fn test_monotonic_generator() {
+        let mut generator = WasmMonotonicGenerator::new();
+        let uids = generator.generate_batch(2048).unwrap();
+        for i in 1..uids.len() {
+            assert!(
+                SmallUid::from(uids[i]) > SmallUid::from(uids[i - 1]),
+                "UIDs are not monotonic at index {}",
+                i
+            );
+        }
+        assert!(SmallUid::from(generator.generate().unwrap()) > SmallUid::from(uids[2047]));
+    }
+}
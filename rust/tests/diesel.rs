@@ -0,0 +1,161 @@
+#![cfg(feature = "diesel")]
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use diesel::{Connection, RunQueryDsl, mysql::Mysql, prelude::*, sqlite::SqliteConnection};
+use small_uid::{SmallUid, SmallUidRange};
+
+diesel::table! {
+    items (id) {
+        id -> BigInt,
+        text_id -> Text,
+        parent -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    mysql_items (id) {
+        id -> Unsigned<BigInt>,
+    }
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[diesel(table_name = items)]
+struct Item {
+    id: SmallUid,
+    text_id: SmallUid,
+    parent: Option<SmallUid>,
+}
+
+/// Opens an in-memory database with one table keyed by SmallUid.
+fn open() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE items (id BIGINT PRIMARY KEY NOT NULL, text_id TEXT NOT NULL, parent BIGINT)",
+    )
+    .execute(&mut connection)
+    .unwrap();
+    connection
+}
+
+fn insert(connection: &mut SqliteConnection, uids: &[SmallUid]) {
+    let rows: Vec<Item> = uids
+        .iter()
+        .map(|&uid| Item {
+            id: uid,
+            text_id: uid,
+            parent: None,
+        })
+        .collect();
+    diesel::insert_into(items::table)
+        .values(&rows)
+        .execute(connection)
+        .unwrap();
+}
+
+#[test]
+fn test_round_trip_and_order() {
+    let mut connection = open();
+    let mut uids = vec![
+        SmallUid(0),
+        SmallUid(1 << 63),
+        SmallUid(u64::MAX),
+        SmallUid::from_timestamp(1_000),
+        SmallUid::new(),
+    ];
+    insert(&mut connection, &uids);
+    uids.sort();
+
+    let rows: Vec<Item> = items::table.order(items::id).load(&mut connection).unwrap();
+    assert_eq!(rows.iter().map(|row| row.id).collect::<Vec<_>>(), uids);
+    assert!(rows.iter().all(|row| row.id == row.text_id));
+    assert!(rows.iter().all(|row| row.parent.is_none()));
+
+    let child = Item {
+        id: SmallUid::new(),
+        text_id: SmallUid::new(),
+        parent: Some(uids[0]),
+    };
+    diesel::insert_into(items::table)
+        .values(&child)
+        .execute(&mut connection)
+        .unwrap();
+    let found: Item = items::table
+        .filter(items::parent.eq(uids[0]))
+        .first(&mut connection)
+        .unwrap();
+    assert_eq!(found, child);
+    let found: SmallUid = items::table
+        .select(items::id)
+        .filter(items::text_id.eq(child.text_id))
+        .first(&mut connection)
+        .unwrap();
+    assert_eq!(found, child.id);
+}
+
+#[test]
+fn test_between() {
+    let mut connection = open();
+    let uids: Vec<SmallUid> = [1_000, 2_000, 3_000]
+        .into_iter()
+        .map(SmallUid::from_timestamp)
+        .collect();
+    insert(&mut connection, &uids);
+
+    let range = SmallUidRange::from_timestamps(1_500..=3_000).unwrap();
+    let found: Vec<SmallUid> = items::table
+        .select(items::id)
+        .filter(small_uid::diesel::between(items::id, range))
+        .order(items::id)
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(found, uids[1..]);
+
+    let found: Vec<SmallUid> = items::table
+        .select(items::id)
+        .filter(small_uid::diesel::between_timestamps(items::id, ..2_000).unwrap())
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(found, uids[..1]);
+
+    let start = UNIX_EPOCH + Duration::from_millis(2_000);
+    let found: Vec<SmallUid> = items::table
+        .select(items::id)
+        .filter(small_uid::diesel::between_datetimes(items::id, start..).unwrap())
+        .order(items::id.desc())
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(found, [uids[2], uids[1]]);
+
+    assert!(small_uid::diesel::between_timestamps(items::id, ..=u64::MAX).is_err());
+}
+
+#[test]
+fn test_invalid_text() {
+    let mut connection = open();
+    diesel::sql_query("INSERT INTO items (id, text_id) VALUES (1, 'not an id')")
+        .execute(&mut connection)
+        .unwrap();
+    let result: QueryResult<SmallUid> = items::table.select(items::text_id).first(&mut connection);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_mysql_binds_the_raw_value() {
+    let uid = SmallUid(u64::MAX);
+    let insert = diesel::insert_into(mysql_items::table).values(mysql_items::id.eq(uid));
+    let sql = diesel::debug_query::<Mysql, _>(&insert).to_string();
+    assert!(sql.ends_with(&format!("-- binds: [{}]", u64::MAX)), "{sql}");
+
+    let range = SmallUidRange::from_timestamps(1_000..2_000).unwrap();
+    let query = mysql_items::table.filter(small_uid::diesel::between(mysql_items::id, range));
+    let sql = diesel::debug_query::<Mysql, _>(&query).to_string();
+    assert!(
+        sql.ends_with(&format!(
+            "-- binds: [{}, {}]",
+            range.start().0,
+            range.end().0
+        )),
+        "{sql}"
+    );
+}
@@ -0,0 +1,69 @@
+/*
+ * Links against the library built with `cargo build --features ffi`:
+ *
+ *   cc tests/ffi/test.c -Iinclude -Ltarget/debug -lsmall_uid -o target/ffi_test
+ *   LD_LIBRARY_PATH=target/debug ./target/ffi_test
+ */
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "small_uid.h"
+
+static void test_new_format_parse(void) {
+    uint64_t id = 0;
+    assert(small_uid_new(&id) == SMALL_UID_STATUS_OK);
+    assert(small_uid_timestamp(id) > 0);
+    assert(small_uid_random(id) < (1 << 20));
+
+    char buffer[SMALL_UID_STRING_SIZE];
+    assert(small_uid_format(id, buffer, sizeof buffer) == SMALL_UID_STATUS_OK);
+    assert(strlen(buffer) == SMALL_UID_STRING_SIZE - 1);
+
+    uint64_t parsed = 0;
+    assert(small_uid_parse(buffer, &parsed) == SMALL_UID_STATUS_OK);
+    assert(parsed == id);
+}
+
+static void test_known_value(void) {
+    char buffer[SMALL_UID_STRING_SIZE];
+    assert(small_uid_format(0x123456789ABCDEF0, buffer, sizeof buffer) == SMALL_UID_STATUS_OK);
+    assert(strcmp(buffer, "EjRWeJq83vA") == 0);
+}
+
+static void test_errors(void) {
+    uint64_t id = 0;
+    char small[SMALL_UID_STRING_SIZE - 1];
+    assert(small_uid_new(NULL) == SMALL_UID_STATUS_NULL_POINTER);
+    assert(small_uid_parse(NULL, &id) == SMALL_UID_STATUS_NULL_POINTER);
+    assert(small_uid_parse("short", &id) == SMALL_UID_STATUS_NOT_A_BASE64_URL);
+    assert(small_uid_format(0, small, sizeof small) == SMALL_UID_STATUS_BUFFER_TOO_SMALL);
+
+    const char *message = small_uid_status_message(SMALL_UID_STATUS_NOT_A_BASE64_URL);
+    assert(strncmp(message, "NotABase64Url", 13) == 0);
+    assert(strcmp(small_uid_status_message(42), "Unknown status") == 0);
+}
+
+static void test_monotonic(void) {
+    SmallUidMonotonicGenerator *generator = small_uid_monotonic_new();
+    assert(generator != NULL);
+    uint64_t previous = 0;
+    for (int i = 0; i < 4096; i++) {
+        uint64_t id = 0;
+        assert(small_uid_monotonic_generate(generator, &id) == SMALL_UID_STATUS_OK);
+        assert(id > previous);
+        previous = id;
+    }
+    small_uid_monotonic_free(generator);
+    small_uid_monotonic_free(NULL);
+    assert(small_uid_monotonic_generate(NULL, &previous) == SMALL_UID_STATUS_NULL_POINTER);
+}
+
+int main(void) {
+    test_new_format_parse();
+    test_known_value();
+    test_errors();
+    test_monotonic();
+    puts("ffi tests passed");
+    return 0;
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "grpc")]
+
+use small_uid::{
+    SmallUid, SmallUidError,
+    grpc::{MAX_BATCH, SmallUidGrpcService, SmallUidServiceClient, SmallUidServiceServer},
+    proto::{DecodeRequest, DecodeResponse, NextBatchRequest, NextRequest, decode_request},
+};
+use tokio::net::TcpListener;
+use tonic::{
+    Code, Status,
+    transport::{Channel, Server, server::TcpIncoming},
+};
+
+/// Starts the service on a free loopback port and returns a client connected to it.
+async fn start() -> SmallUidServiceClient<Channel> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(SmallUidServiceServer::new(SmallUidGrpcService::default()))
+            .serve_with_incoming(TcpIncoming::from(listener))
+            .await
+            .unwrap();
+    });
+    SmallUidServiceClient::connect(format!("http://{}", address))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_ids_are_globally_ordered() {
+    let mut client = start().await;
+    let first: SmallUid = client
+        .next(NextRequest {})
+        .await
+        .unwrap()
+        .into_inner()
+        .into();
+
+    let response = client
+        .next_batch(NextBatchRequest { count: 3000 })
+        .await
+        .unwrap()
+        .into_inner();
+    let uids: Vec<SmallUid> = response.ids.into_iter().map(SmallUid::from).collect();
+    assert_eq!(uids.len(), 3000);
+    assert!(first < uids[0]);
+    assert!(uids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    for count in [0, MAX_BATCH as u32 + 1] {
+        let status = client
+            .next_batch(NextBatchRequest { count })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+}
+
+#[tokio::test]
+async fn test_decode() {
+    let mut client = start().await;
+    let uid = SmallUid::from_parts(1_700_000_000_123, 0x5903C);
+
+    let by_uid = client
+        .decode(DecodeRequest {
+            id: Some(decode_request::Id::Uid(uid.into())),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    let by_text = client
+        .decode(DecodeRequest {
+            id: Some(decode_request::Id::Text(uid.to_string())),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    // The age is measured at each request, everything else is the same
+    assert_eq!(
+        DecodeResponse {
+            age_ms: 0,
+            ..by_uid.clone()
+        },
+        DecodeResponse {
+            age_ms: 0,
+            ..by_text
+        }
+    );
+    assert_eq!(by_uid.utc, "2023-11-14T22:13:20.123Z");
+    assert_eq!(by_uid.random, 0x5903C);
+
+    for id in [
+        None,
+        Some(decode_request::Id::Text("not an id".to_string())),
+    ] {
+        let status = client.decode(DecodeRequest { id }).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_error_status_codes() {
+    let code = |err: SmallUidError| Status::from(err).code();
+    assert_eq!(code(SmallUidError::NotABase64Url), Code::InvalidArgument);
+    assert_eq!(code(SmallUidError::InvalidChar), Code::InvalidArgument);
+    assert_eq!(
+        code(SmallUidError::MonotonicCounterLimit),
+        Code::Unavailable
+    );
+    assert_eq!(code(SmallUidError::BeforeEpoch), Code::Internal);
+    assert_eq!(code(SmallUidError::TimestampLimit), Code::Internal);
+}
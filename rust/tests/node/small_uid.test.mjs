@@ -0,0 +1,51 @@
+// Runs against the addon built from this crate:
+//
+//   npm run build && npm test
+
+import assert from "node:assert/strict";
+import { createRequire } from "node:module";
+import { test } from "node:test";
+
+const require = createRequire(import.meta.url);
+const { MonotonicGenerator, SmallUid } = require("../../small_uid.node");
+
+test("new SmallUid", () => {
+  const uid = new SmallUid();
+  assert.equal(typeof uid.toBigInt(), "bigint");
+  assert.ok(Math.abs(uid.timestamp() - Date.now()) < 5_000);
+  assert.ok(uid.random() >= 0 && uid.random() < 2 ** 20);
+});
+
+test("strings match Display", () => {
+  const uid = SmallUid.fromBigInt(0x123456789abcdef0n);
+  assert.equal(uid.toString(), "EjRWeJq83vA");
+  assert.equal(`${uid}`, "EjRWeJq83vA");
+  assert.equal(SmallUid.fromString("EjRWeJq83vA").toBigInt(), 0x123456789abcdef0n);
+});
+
+test("bigint round trip", () => {
+  const uid = new SmallUid();
+  assert.equal(SmallUid.fromBigInt(uid.toBigInt()).toString(), uid.toString());
+  assert.equal(SmallUid.fromBigInt(2n ** 64n - 1n).toBigInt(), 2n ** 64n - 1n);
+  assert.throws(() => SmallUid.fromBigInt(2n ** 64n));
+  assert.throws(() => SmallUid.fromBigInt(-1n));
+});
+
+test("parts", () => {
+  const uid = SmallUid.fromBigInt((1_700_000_000_123n << 20n) | 0xabcden);
+  assert.equal(uid.timestamp(), 1_700_000_000_123);
+  assert.equal(uid.random(), 0xabcde);
+});
+
+test("parse errors", () => {
+  assert.throws(() => SmallUid.fromString("short"), /NotABase64Url/);
+});
+
+test("MonotonicGenerator", () => {
+  const generator = new MonotonicGenerator();
+  const uids = generator.generateBatch(4096).map((uid) => uid.toBigInt());
+  uids.push(generator.generate().toBigInt());
+  for (let i = 1; i < uids.length; i++) {
+    assert.ok(uids[i] > uids[i - 1], `UIDs are not monotonic at index ${i}`);
+  }
+});
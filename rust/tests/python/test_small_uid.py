@@ -0,0 +1,66 @@
+# Runs against the extension built from this crate:
+#
+#   maturin build --release --out dist && pip install dist/*.whl
+#   pytest
+
+from datetime import datetime, timedelta, timezone
+from itertools import islice
+
+import pytest
+
+from small_uid import MonotonicGenerator, SmallUid, SmallUidError, parse
+
+
+def test_new():
+    uid = SmallUid()
+    now = datetime.now(timezone.utc)
+    assert 0 <= uid.random < 1 << 20
+    assert abs(uid.datetime - now) < timedelta(seconds=5)
+
+
+def test_string_round_trip():
+    uid = SmallUid.from_int(0x123456789ABCDEF0)
+    assert str(uid) == "EjRWeJq83vA"
+    assert repr(uid) == "SmallUid('EjRWeJq83vA')"
+    assert SmallUid.parse(str(uid)) == uid
+    assert parse(str(uid)) == uid
+
+
+def test_int_round_trip():
+    uid = SmallUid()
+    assert SmallUid.from_int(int(uid)) == uid
+    assert int(SmallUid.from_int(2**64 - 1)) == 2**64 - 1
+    with pytest.raises(OverflowError):
+        SmallUid.from_int(2**64)
+
+
+def test_parts():
+    uid = SmallUid.from_int(1_700_000_000_123 << 20 | 0xABCDE)
+    assert uid.timestamp == 1_700_000_000_123
+    assert uid.random == 0xABCDE
+    assert uid.datetime == datetime(2023, 11, 14, 22, 13, 20, 123000, tzinfo=timezone.utc)
+
+
+def test_comparisons():
+    a = SmallUid.from_int(1)
+    b = SmallUid.from_int(2)
+    assert a < b and a <= b and b > a and b >= a and a != b
+    assert a == SmallUid.from_int(1)
+    assert len({a, b, SmallUid.from_int(1)}) == 2
+    assert sorted([b, a]) == [a, b]
+
+
+def test_parse_errors():
+    with pytest.raises(SmallUidError):
+        parse("short")
+    with pytest.raises(ValueError):
+        SmallUid.parse("not a small uid")
+
+
+def test_monotonic_generator():
+    generator = MonotonicGenerator()
+    uids = generator.generate_batch(4096)
+    uids.append(generator.generate())
+    uids.extend(islice(generator, 10))
+    assert len(uids) == 4107
+    assert all(a < b for a, b in zip(uids, uids[1:]))
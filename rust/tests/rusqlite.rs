@@ -0,0 +1,122 @@
+#![cfg(feature = "rusqlite")]
+
+use rusqlite::{Connection, params};
+use small_uid::{SmallUid, SmallUidRange, rusqlite::register_functions};
+
+/// Opens an in-memory database with the SQL functions and one table keyed by SmallUid.
+fn open() -> Connection {
+    let connection = Connection::open_in_memory().unwrap();
+    register_functions(&connection).unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE items (
+                id INTEGER PRIMARY KEY DEFAULT (small_uid_monotonic()),
+                text_id TEXT
+            )",
+        )
+        .unwrap();
+    connection
+}
+
+#[test]
+fn test_round_trip_and_order() {
+    let connection = open();
+    let mut uids = vec![
+        SmallUid(0),
+        SmallUid(1 << 63),
+        SmallUid(u64::MAX),
+        SmallUid::from_timestamp(1_000),
+        SmallUid::new(),
+    ];
+    for uid in &uids {
+        connection
+            .execute(
+                "INSERT INTO items (id, text_id) VALUES (?1, ?2)",
+                params![uid, uid.to_string()],
+            )
+            .unwrap();
+    }
+    uids.sort();
+
+    let mut statement = connection
+        .prepare("SELECT id, text_id FROM items ORDER BY id")
+        .unwrap();
+    let rows: Vec<(SmallUid, SmallUid)> = statement
+        .query_map((), |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows.iter().map(|row| row.0).collect::<Vec<_>>(), uids);
+    assert!(rows.iter().all(|(id, text)| id == text));
+
+    let range = SmallUidRange::from_timestamps(..=1_000).unwrap();
+    let count: i64 = connection
+        .query_row(
+            "SELECT count(*) FROM items WHERE id BETWEEN ?1 AND ?2",
+            params![range.start(), range.end()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_generating_functions() {
+    let connection = open();
+    for _ in 0..100 {
+        connection
+            .execute("INSERT INTO items DEFAULT VALUES", ())
+            .unwrap();
+    }
+    let mut statement = connection.prepare("SELECT id FROM items").unwrap();
+    let uids: Vec<SmallUid> = statement
+        .query_map((), |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    // Monotonic ids are inserted in increasing order
+    assert_eq!(uids.len(), 100);
+    assert!(uids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let (a, b): (SmallUid, SmallUid) = connection
+        .query_row("SELECT small_uid(), small_uid()", (), |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_decoding_functions() {
+    let connection = open();
+    let uid = SmallUid::from_parts(1_700_000_000_123, 0x5903C);
+    let (timestamp, text, from_text): (i64, String, SmallUid) = connection
+        .query_row(
+            "SELECT small_uid_timestamp(?1), small_uid_to_text(?1), small_uid_from_text(?2)",
+            params![uid, uid.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(timestamp, 1_700_000_000_123);
+    assert_eq!(text, uid.to_string());
+    assert_eq!(from_text, uid);
+
+    // Text ids are accepted too, NULL stays NULL
+    let (timestamp, null): (i64, Option<String>) = connection
+        .query_row(
+            "SELECT small_uid_timestamp(?1), small_uid_to_text(NULL)",
+            params![uid.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(timestamp, 1_700_000_000_123);
+    assert_eq!(null, None);
+
+    assert!(
+        connection
+            .query_row("SELECT small_uid_from_text('not an id')", (), |row| {
+                row.get::<_, i64>(0)
+            })
+            .is_err()
+    );
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "server")]
+
+use std::sync::Arc;
+
+use small_uid::{SmallUid, server::router};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// Starts the service on a free loopback port and returns its address.
+async fn start() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    tokio::spawn(async move {
+        axum::serve(listener, router(Arc::default())).await.unwrap();
+    });
+    address
+}
+
+/// Sends a GET request and returns the status code and body.
+async fn get(address: &str, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        path, address
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
+
+#[tokio::test]
+async fn test_ids_are_globally_ordered() {
+    let address = start().await;
+    let (status, body) = get(&address, "/id").await;
+    assert_eq!(status, 200);
+    let first = SmallUid::try_from(body).unwrap();
+
+    let (status, body) = get(&address, "/ids?n=3000").await;
+    assert_eq!(status, 200);
+    let uids: Vec<SmallUid> = body
+        .lines()
+        .map(|line| SmallUid::try_from(line.to_string()).unwrap())
+        .collect();
+    assert_eq!(uids.len(), 3000);
+    assert!(first < uids[0]);
+    assert!(uids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let clients: Vec<_> = (0..8)
+        .map(|_| {
+            let address = address.clone();
+            tokio::spawn(async move { get(&address, "/ids?n=500").await.1 })
+        })
+        .collect();
+    let mut all: Vec<SmallUid> = Vec::new();
+    for client in clients {
+        let body = client.await.unwrap();
+        all.extend(
+            body.lines()
+                .map(|line| SmallUid::try_from(line.to_string()).unwrap()),
+        );
+    }
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 4000);
+    assert!(uids[2999] < all[0]);
+}
+
+#[tokio::test]
+async fn test_decode() {
+    let address = start().await;
+    let uid = SmallUid::from_parts(1_700_000_000_123, 0x5903C);
+    let (status, body) = get(&address, &format!("/decode/{}", uid)).await;
+    assert_eq!(status, 200);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["id"], uid.to_string());
+    assert_eq!(json["utc"], "2023-11-14T22:13:20.123Z");
+
+    let (status, body) = get(&address, "/decode/short").await;
+    assert_eq!(status, 400);
+    assert!(body.starts_with("NotABase64Url"));
+}
+
+#[tokio::test]
+async fn test_limits_health_and_metrics() {
+    let address = start().await;
+    assert_eq!(get(&address, "/ids?n=0").await.0, 400);
+    assert_eq!(get(&address, "/ids?n=10001").await.0, 400);
+    assert_eq!(get(&address, "/ids?n=abc").await.0, 400);
+    assert_eq!(get(&address, "/health").await, (200, "ok".to_string()));
+
+    get(&address, "/ids?n=5").await;
+    let (status, body) = get(&address, "/metrics").await;
+    assert_eq!(status, 200);
+    assert!(body.contains("small_uid_ids_issued_total 5\n"));
+    assert!(body.contains("small_uid_errors_total 3\n"));
+    assert!(body.contains("small_uid_requests_total 4\n"));
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "sqlx")]
+
+use small_uid::{SmallUid, SmallUidRange, sqlx::AsText};
+use sqlx::{Row, SqlitePool};
+
+/// Opens an in-memory database with one table keyed by SmallUid.
+async fn open() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE items (id INTEGER PRIMARY KEY, text_id TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    pool
+}
+
+async fn insert(pool: &SqlitePool, uid: SmallUid) {
+    sqlx::query("INSERT INTO items (id, text_id) VALUES (?, ?)")
+        .bind(uid)
+        .bind(AsText(uid))
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_round_trip_and_order() {
+    let pool = open().await;
+    let mut uids = vec![
+        SmallUid(0),
+        SmallUid(1 << 63),
+        SmallUid(u64::MAX),
+        SmallUid::from_timestamp(1_000),
+        SmallUid::new(),
+    ];
+    for &uid in &uids {
+        insert(&pool, uid).await;
+    }
+    uids.sort();
+
+    let rows: Vec<(SmallUid, AsText)> = sqlx::query_as("SELECT id, text_id FROM items ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(rows.iter().map(|row| row.0).collect::<Vec<_>>(), uids);
+    assert!(rows.iter().all(|(id, text)| *id == text.0));
+}
+
+#[tokio::test]
+async fn test_range_query() {
+    let pool = open().await;
+    for timestamp in [1_000, 2_000, 3_000] {
+        insert(&pool, SmallUid::from_timestamp(timestamp)).await;
+    }
+    let range = SmallUidRange::from_timestamps(1_500..=3_000).unwrap();
+    let found: Vec<SmallUid> =
+        sqlx::query_scalar("SELECT id FROM items WHERE id BETWEEN ? AND ? ORDER BY id")
+            .bind(range.start())
+            .bind(range.end())
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    let timestamps: Vec<u64> = found.iter().map(SmallUid::get_timestamp).collect();
+    assert_eq!(timestamps, [2_000, 3_000]);
+}
+
+#[tokio::test]
+async fn test_invalid_text() {
+    let pool = open().await;
+    sqlx::query("INSERT INTO items (id, text_id) VALUES (1, 'not an id')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let row = sqlx::query("SELECT text_id FROM items")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(row.try_get::<AsText, _>("text_id").is_err());
+}
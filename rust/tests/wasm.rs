@@ -0,0 +1,50 @@
+#![cfg(all(target_arch = "wasm32", feature = "serde"))]
+
+use small_uid::{SmallUid, js_safe::JsSafeUid};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_serde_json_round_trip() {
+    let uid = SmallUid::new();
+    let json = serde_json::to_string(&uid).unwrap();
+    assert_eq!(json, format!("\"{}\"", uid));
+    assert_eq!(serde_json::from_str::<SmallUid>(&json).unwrap(), uid);
+}
+
+#[wasm_bindgen_test]
+fn test_serde_wasm_bindgen_round_trip() {
+    let uid = SmallUid::new();
+    let value: JsValue = serde_wasm_bindgen::to_value(&uid).unwrap();
+    assert_eq!(value.as_string(), Some(uid.to_string()));
+    assert_eq!(
+        serde_wasm_bindgen::from_value::<SmallUid>(value).unwrap(),
+        uid
+    );
+
+    let uid = JsSafeUid::new();
+    let value: JsValue = serde_wasm_bindgen::to_value(&uid).unwrap();
+    assert_eq!(value.as_f64(), Some(uid.to_f64()));
+    assert_eq!(
+        serde_wasm_bindgen::from_value::<JsSafeUid>(value).unwrap(),
+        uid
+    );
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen_test]
+fn test_exported_classes() {
+    use small_uid::{WasmMonotonicGenerator, WasmSmallUid};
+
+    let uid = WasmSmallUid::new();
+    let parsed = WasmSmallUid::from_string(&uid.to_js_string()).unwrap();
+    assert_eq!(parsed.to_big_int(), uid.to_big_int());
+    assert!(WasmSmallUid::from_string("not an id").is_err());
+
+    let mut generator = WasmMonotonicGenerator::new();
+    let uids = generator.generate_batch(2048).unwrap();
+    assert!(
+        uids.windows(2)
+            .all(|w| w[0].to_big_int() < w[1].to_big_int())
+    );
+}